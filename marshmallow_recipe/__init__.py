@@ -3,11 +3,37 @@ import re
 import sys
 
 from .bake import bake_schema, get_field_for
-from .metadata import decimal_metadata, metadata
+from .fields import IntRange, TimestampUnit, UuidFormat
+from .json_schema import json_schema
+from .metadata import (
+    bool_metadata,
+    custom_metadata,
+    date_metadata,
+    datetime_metadata,
+    decimal_metadata,
+    dict_metadata,
+    enum_metadata,
+    float_metadata,
+    int_metadata,
+    metadata,
+    uuid_metadata,
+)
 from .missing import MISSING
 from .naming_case import CAMEL_CASE, CAPITAL_CAMEL_CASE, DEFAULT_CASE, CamelCase, CapitalCamelCase, NamingCase
 from .options import NoneValueHandling, options
-from .serialization import EmptySchema, dump, dump_many, load, load_many, schema
+from .serialization import (
+    EmptySchema,
+    clear_cache,
+    dump,
+    dump_many,
+    load,
+    load_many,
+    registered_classes,
+    schema,
+    unregister,
+    validate,
+    warm,
+)
 
 __all__: tuple[str, ...] = (
     "bake_schema",
@@ -26,9 +52,27 @@ __all__: tuple[str, ...] = (
     "dump",
     "dump_many",
     "schema",
+    "validate",
+    "json_schema",
+    "unregister",
+    "clear_cache",
+    "registered_classes",
+    "warm",
     "EmptySchema",
     "metadata",
+    "bool_metadata",
+    "int_metadata",
     "decimal_metadata",
+    "float_metadata",
+    "enum_metadata",
+    "custom_metadata",
+    "datetime_metadata",
+    "date_metadata",
+    "dict_metadata",
+    "TimestampUnit",
+    "uuid_metadata",
+    "UuidFormat",
+    "IntRange",
 )
 
 __version__ = "0.0.11"
@@ -1,71 +1,272 @@
+import contextlib
+import contextvars
 import dataclasses
 import datetime
 import decimal
 import enum
+import fractions
 import inspect
+import pathlib
+import sys
 import types
 import uuid
-from typing import Any, Dict, Generic, List, Mapping, Type, TypeVar, cast
+from typing import (
+    Any,
+    Callable,
+    Dict,
+    Generic,
+    Iterator,
+    List,
+    Mapping,
+    Sequence,
+    Type,
+    TypeVar,
+    cast,
+    get_args,
+    get_type_hints,
+    is_typeddict,
+)
 
 import marshmallow as m
 import typing_inspect
 
 from .fields import (
     bool_field,
+    complex_field,
+    custom_field,
     date_field,
     datetime_field,
     decimal_field,
     dict_field,
     enum_field,
     float_field,
+    fraction_field,
     int_field,
+    is_dump_validation_in_progress,
     list_field,
     nested_field,
+    path_field,
     raw_field,
+    set_dump_default,
     str_field,
     uuid_field,
 )
+from .missing import MISSING
 from .naming_case import NamingCase
-from .options import NoneValueHandling, get_options_for
+from .options import DataclassOptions, NoneValueHandling, get_options_for
 
 _T = TypeVar("_T")
 _MARSHMALLOW_VERSION_MAJOR = int(m.__version__.split(".")[0])
 
+# validate checks data without constructing objects, so nested schemas return loaded values as is then
+_is_validation: contextvars.ContextVar[bool] = contextvars.ContextVar("is_validation", default=False)
+
+
+@contextlib.contextmanager
+def validation() -> Iterator[None]:
+    token = _is_validation.set(True)
+    try:
+        yield
+    finally:
+        _is_validation.reset(token)
+
 
 def bake_schema(
     cls: Type[_T],
     *,
     naming_case: NamingCase | None = None,
 ) -> Type[m.Schema]:
-    if not dataclasses.is_dataclass(cls):
-        raise ValueError(f"{cls} is not a dataclass")
-
     options = get_options_for(cls)
     if naming_case is None:
         naming_case = options.naming_case
 
-    fields = dataclasses.fields(cls)
-    schema_class = type(
-        cls.__name__,
-        (_get_base_schema(cls, options.none_value_handling),),
-        {
-            field.name: get_field_for(
+    if is_typeddict(cls):
+        # keys of a total=False typed dict can be absent, so they are loaded and dumped as MISSING ones
+        required_keys = getattr(cls, "__required_keys__", frozenset())
+        return _bake_schema(
+            cls,
+            options,
+            [
+                (
+                    key,
+                    key_type,
+                    _get_metadata(
+                        name=naming_case(key),
+                        default=dataclasses.MISSING if key in required_keys else MISSING,
+                        metadata={},
+                    ),
+                )
+                for key, key_type in get_type_hints(cls).items()
+            ],
+            naming_case=naming_case,
+        )
+
+    if _is_named_tuple(cls):
+        field_defaults = getattr(cls, "_field_defaults")
+        return _bake_schema(
+            cls,
+            options,
+            [
+                (
+                    name,
+                    field_type,
+                    _get_metadata(
+                        name=naming_case(name),
+                        default=field_defaults.get(name, dataclasses.MISSING),
+                        metadata={},
+                    ),
+                )
+                for name, field_type in get_type_hints(cls).items()
+            ],
+            naming_case=naming_case,
+            # a named tuple is also loaded from an array even if it is dumped as an object
+            load_from_array=True,
+        )
+
+    if not dataclasses.is_dataclass(cls):
+        raise ValueError(f"{cls} is not a dataclass")
+
+    fields = [field for field in dataclasses.fields(cls) if field.init]
+    return _bake_schema(
+        cls,
+        options,
+        [
+            (
+                field.name,
                 field.type,
-                _get_metadata(name=naming_case(field.name), default=_get_field_default(field), metadata=field.metadata),
-                naming_case=naming_case,
+                _get_metadata(
+                    name=naming_case(field.name),
+                    default=_get_field_default(field, all_optional=options.all_optional),
+                    metadata=field.metadata,
+                ),
             )
             for field in fields
-            if field.init
+        ],
+        naming_case=naming_case,
+        missing_fields=[
+            field.name for field in fields if options.all_optional and field.default is dataclasses.MISSING
+        ],
+    )
+
+
+def _bake_schema(
+    cls: type,
+    options: DataclassOptions,
+    fields_with_metadata: Sequence[tuple[str, Any, Mapping[str, Any]]],
+    *,
+    naming_case: NamingCase,
+    missing_fields: Sequence[str] = (),
+    load_from_array: bool = False,
+) -> Type[m.Schema]:
+    names = [metadata["name"] for _, _, metadata in fields_with_metadata]
+    positional_names: list[str] | None = None
+    if options.positional:
+        if options.computed_fields or any(
+            metadata.get("load_only") or metadata.get("dump_only") for _, _, metadata in fields_with_metadata
+        ):
+            raise ValueError(f"{cls} is positional, so it cannot have load only, dump only or computed fields")
+        positional_names = names
+    schema_class = type(
+        cls.__name__,
+        (
+            _get_base_schema(
+                cls,
+                options,
+                missing_fields,
+                format_specs={
+                    metadata["name"]: (name, metadata["format_spec"])
+                    for name, _, metadata in fields_with_metadata
+                    if "format_spec" in metadata
+                },
+                dump_validators={
+                    metadata["name"]: (name, metadata["dump_validate"])
+                    for name, _, metadata in fields_with_metadata
+                    if "dump_validate" in metadata
+                },
+                strict_types={
+                    metadata["name"]: strict_type
+                    for _, field_type, metadata in fields_with_metadata
+                    if options.strict_types and (strict_type := _get_strict_type(field_type)) is not None
+                },
+                none_value_handlings={
+                    metadata["name"]: metadata["none_value_handling"]
+                    for _, _, metadata in fields_with_metadata
+                    if "none_value_handling" in metadata
+                },
+                pre_loads={
+                    metadata["name"]: metadata["pre_load"]
+                    for _, _, metadata in fields_with_metadata
+                    if "pre_load" in metadata
+                },
+                array_names=names if options.positional or load_from_array else None,
+                positional_names=positional_names,
+            ),
+        ),
+        {
+            **{
+                name: _with_field_flags(
+                    _with_dump_default(
+                        get_field_for(
+                            field_type,
+                            metadata,
+                            naming_case=naming_case,
+                            max_items=options.max_items,
+                            max_keys=options.max_keys,
+                        ),
+                        metadata,
+                    ),
+                    metadata,
+                )
+                for name, field_type, metadata in fields_with_metadata
+            },
+            **{
+                name: _as_dump_only(
+                    get_field_for(
+                        type,
+                        _get_metadata(name=naming_case(name), default=dataclasses.MISSING, metadata={}),
+                        naming_case=naming_case,
+                    )
+                )
+                for name, type in options.computed_fields.items()
+            },
         },
     )
     return cast(Type[m.Schema], schema_class)
 
 
+def get_nested_classes(cls: type) -> list[type]:
+    """
+    Returns classes with their own schemas, which are referenced by fields of cls directly or through unions,
+    lists and dicts
+    """
+    if is_typeddict(cls) or _is_named_tuple(cls):
+        field_types = list(get_type_hints(cls).values())
+    else:
+        field_types = [field.type for field in dataclasses.fields(cls) if field.init]
+    field_types.extend(get_options_for(cls).computed_fields.values())
+
+    result: list[type] = []
+    while field_types:
+        field_type = field_types.pop(0)
+        if dataclasses.is_dataclass(field_type) or is_typeddict(field_type) or _is_named_tuple(field_type):
+            if field_type not in result:
+                result.append(field_type)
+        else:
+            field_types.extend(get_args(field_type))
+    return result
+
+
+def _is_named_tuple(type: Any) -> bool:
+    return inspect.isclass(type) and issubclass(type, tuple) and hasattr(type, "_fields")
+
+
 def get_field_for(
     type: Type[_T],
     metadata: Mapping[str, Any],
     *,
     naming_case: NamingCase,
+    max_items: int | None = None,
+    max_keys: int | None = None,
 ) -> m.fields.Field:
     if type is Any:
         return raw_field(**metadata)
@@ -88,6 +289,9 @@ def get_field_for(
     else:
         required = True
 
+    if "deserializer" in metadata:
+        return custom_field(required=required, **metadata)
+
     field_factory = _SIMPLE_TYPE_FIELD_FACTORIES.get(type)
     if field_factory:
         typed_field_factory = cast(_FieldFactory[_T], field_factory)
@@ -96,7 +300,10 @@ def get_field_for(
     if inspect.isclass(type) and issubclass(type, enum.Enum):
         return enum_field(enum_type=type, required=required, **metadata)
 
-    if dataclasses.is_dataclass(type):
+    if inspect.isclass(type) and issubclass(type, pathlib.PurePath):
+        return path_field(path_type=type, required=required, **metadata)
+
+    if dataclasses.is_dataclass(type) or is_typeddict(type) or _is_named_tuple(type):
         return nested_field(
             bake_schema(type, naming_case=naming_case),
             required=required,
@@ -107,13 +314,27 @@ def get_field_for(
         arguments = typing_inspect.get_args(type, True)
         if origin in (list, List):
             return list_field(
-                get_field_for(arguments[0], metadata={}, naming_case=naming_case),
+                get_field_for(
+                    arguments[0], metadata={}, naming_case=naming_case, max_items=max_items, max_keys=max_keys
+                ),
                 required=required,
+                max_items=max_items,
                 **metadata,
             )
-        if origin in (dict, Dict) and arguments[0] is str and arguments[1] is Any:
+        if origin in (dict, Dict):
+            if arguments[0] is str and arguments[1] is Any:
+                return dict_field(
+                    required=required,
+                    max_keys=max_keys,
+                    **metadata,
+                )
             return dict_field(
+                key_field=get_field_for(arguments[0], metadata={}, naming_case=naming_case),
+                value_field=get_field_for(
+                    arguments[1], metadata={}, naming_case=naming_case, max_items=max_items, max_keys=max_keys
+                ),
                 required=required,
+                max_keys=max_keys,
                 **metadata,
             )
 
@@ -122,44 +343,288 @@ def get_field_for(
 
 if _MARSHMALLOW_VERSION_MAJOR >= 3:
 
-    def _get_base_schema(cls: Type[_T], none_value_handling: NoneValueHandling) -> Type[m.Schema]:
+    def _get_base_schema(
+        cls: Type[_T],
+        options: DataclassOptions,
+        missing_fields: Sequence[str],
+        format_specs: Mapping[str, tuple[str, str]],
+        dump_validators: Mapping[str, tuple[str, Callable[[Any], Any]]],
+        strict_types: Mapping[str, tuple[type, str]],
+        none_value_handlings: Mapping[str, NoneValueHandling],
+        pre_loads: Mapping[str, Callable[[Any], Any]],
+        array_names: Sequence[str] | None,
+        positional_names: Sequence[str] | None,
+    ) -> Type[m.Schema]:
         class _Schema(m.Schema):
             class Meta:
                 unknown = m.EXCLUDE
 
+            formatted_names = frozenset(attr for attr, _ in format_specs.values())
+
+            @m.pre_load
+            def pre_load(self, data: Any, **_: Any) -> Any:
+                return _pre_load(data, options, strict_types, pre_loads, array_names, format_specs)
+
+            @m.pre_dump
+            def validate_on_dump(self, obj: Any, **_: Any) -> Any:
+                _validate_on_dump(obj, dump_validators)
+                return obj
+
             @m.post_dump
             def remove_none_values(self, data: dict[str, Any], **_: Any) -> dict[str, Any]:
-                if none_value_handling == NoneValueHandling.IGNORE:
-                    return {key: value for key, value in data.items() if value is not None}
+                return {
+                    key: value
+                    for key, value in data.items()
+                    if value is not None
+                    or none_value_handlings.get(key, options.none_value_handling) == NoneValueHandling.INCLUDE
+                }
+
+            @m.post_dump(pass_original=True)
+            def format_values(self, data: dict[str, Any], original: Any, **_: Any) -> dict[str, Any]:
+                for key, (attr, format_spec) in format_specs.items():
+                    value = getattr(original, attr, MISSING)
+                    if value is not MISSING and data.get(key) is not None:
+                        data[key] = format(value, format_spec)
                 return data
 
+            @m.post_dump
+            def to_positional(self, data: dict[str, Any], **_: Any) -> Any:
+                return _to_positional(data, positional_names)
+
+            @m.validates_schema(skip_on_field_errors=False)
+            def validate_schema(self, data: dict[str, Any], **_: Any) -> None:
+                _validate_schema(data, options)
+
             @m.post_load
             def post_load(self, data: dict[str, Any], **_: Any) -> Any:
-                return cls(**data)
+                if _is_validation.get():
+                    return data
+                loaded = cls(**{**dict.fromkeys(missing_fields, MISSING), **data})
+                return loaded if options.post_load is None else options.post_load(loaded)
 
         return _Schema
 
 else:
 
-    def _get_base_schema(cls: Type[_T], none_value_handling: NoneValueHandling) -> Type[m.Schema]:
+    def _get_base_schema(
+        cls: Type[_T],
+        options: DataclassOptions,
+        missing_fields: Sequence[str],
+        format_specs: Mapping[str, tuple[str, str]],
+        dump_validators: Mapping[str, tuple[str, Callable[[Any], Any]]],
+        strict_types: Mapping[str, tuple[type, str]],
+        none_value_handlings: Mapping[str, NoneValueHandling],
+        pre_loads: Mapping[str, Callable[[Any], Any]],
+        array_names: Sequence[str] | None,
+        positional_names: Sequence[str] | None,
+    ) -> Type[m.Schema]:
         class _Schema(m.Schema):  # type: ignore
+            class Meta:
+                # marshmallow2 keeps field names in a set unless ordered, so a dump order would be random
+                ordered = True
+
+            formatted_names = frozenset(attr for attr, _ in format_specs.values())
+
+            @m.pre_load  # type: ignore
+            def pre_load(self, data: Any) -> Any:
+                return _pre_load(data, options, strict_types, pre_loads, array_names, format_specs)
+
+            @m.pre_dump  # type: ignore
+            def validate_on_dump(self, obj: Any) -> Any:
+                _validate_on_dump(obj, dump_validators)
+                return obj
+
             @m.post_dump  # type: ignore
             def remove_none_values(self, data: dict[str, Any]) -> dict[str, Any]:
-                if none_value_handling == NoneValueHandling.IGNORE:
-                    return {key: value for key, value in data.items() if value is not None}
+                return {
+                    key: value
+                    for key, value in data.items()
+                    if value is not None
+                    or none_value_handlings.get(key, options.none_value_handling) == NoneValueHandling.INCLUDE
+                }
+
+            @m.post_dump(pass_original=True)  # type: ignore
+            def format_values(self, data: dict[str, Any], original: Any) -> dict[str, Any]:
+                for key, (attr, format_spec) in format_specs.items():
+                    value = getattr(original, attr, MISSING)
+                    if value is not MISSING and data.get(key) is not None:
+                        data[key] = format(value, format_spec)
                 return data
 
+            @m.post_dump  # type: ignore
+            def to_positional(self, data: dict[str, Any]) -> Any:
+                return _to_positional(data, positional_names)
+
+            @m.validates_schema(skip_on_field_errors=False)  # type: ignore
+            def validate_schema(self, data: dict[str, Any]) -> None:
+                _validate_schema(data, options)
+
             @m.post_load  # type: ignore
             def post_load(self, data: dict[str, Any]) -> Any:
-                return cls(**data)
+                if _is_validation.get():
+                    return data
+                loaded = cls(**{**dict.fromkeys(missing_fields, MISSING), **data})
+                return loaded if options.post_load is None else options.post_load(loaded)
 
         return _Schema
 
 
-def _get_field_default(field: dataclasses.Field[_T]) -> Any:
+# hooks of a schema are invoked in alphabetical order, so the steps are chained in a single one
+def _pre_load(
+    data: Any,
+    options: DataclassOptions,
+    strict_types: Mapping[str, tuple[type, str]],
+    pre_loads: Mapping[str, Callable[[Any], Any]],
+    array_names: Sequence[str] | None,
+    format_specs: Mapping[str, tuple[str, str]],
+) -> Any:
+    # values of an array go in the order of fields
+    if array_names is not None and isinstance(data, (list, tuple)):
+        if len(data) > len(array_names):
+            raise m.ValidationError(f"Too many items, expected at most {len(array_names)}.")
+        data = dict(zip(array_names, data))
+    # marshmallow3 validates dumped data by loading it, so hooks which expect raw input are skipped then
+    is_dump_validation = is_dump_validation_in_progress()
+    if is_dump_validation and format_specs and isinstance(data, dict):
+        # a formatted value cannot be loaded back in general, e.g. a date formatted by strftime
+        data = {key: value for key, value in data.items() if key not in format_specs}
+    if options.pre_load is not None and not is_dump_validation:
+        data = options.pre_load(data)
+    if options.strip_all_strings:
+        data = _strip_strings(data)
+    if options.intern_strings:
+        data = _intern_strings(data)
+    if pre_loads and isinstance(data, dict) and not is_dump_validation:
+        data = dict(data)
+        for key, pre_load in pre_loads.items():
+            if key not in data:
+                continue
+            value = pre_load(data[key])
+            if value is MISSING:
+                del data[key]
+            else:
+                data[key] = value
+    _check_strict_types(data, strict_types)
+    return data
+
+
+def _strip_strings(data: Any) -> Any:
+    return _map_strings(data, str.strip)
+
+
+def _map_strings(data: Any, transform: Callable[[str], str]) -> Any:
+    # an explicit stack instead of recursion, so deeply nested values of Any and dict fields
+    # cannot exceed the recursion limit, dicts and lists are copied to keep raw data untouched
+    root = [data]
+    stack: list[tuple[Any, Any]] = [(root, 0)]
+    while stack:
+        container, key = stack.pop()
+        value = container[key]
+        if isinstance(value, str):
+            container[key] = transform(value)
+        elif isinstance(value, dict):
+            container[key] = dict_copy = dict(value)
+            stack.extend((dict_copy, item_key) for item_key in dict_copy)
+        elif isinstance(value, list):
+            container[key] = list_copy = list(value)
+            stack.extend((list_copy, index) for index in range(len(list_copy)))
+    return root[0]
+
+
+def _intern_strings(data: Any) -> Any:
+    return _map_strings(data, _intern_string)
+
+
+def _intern_string(value: str) -> str:
+    # sys.intern accepts exactly str, not its subclasses
+    return sys.intern(value) if type(value) is str else value
+
+
+def _check_strict_types(data: Any, strict_types: Mapping[str, tuple[type, str]]) -> None:
+    if not isinstance(data, dict):
+        return
+    errors: dict[str, Any] = {}
+    for key, (strict_type, error) in strict_types.items():
+        value = data.get(key)
+        # bool is a subclass of int, so the exact type is compared
+        if value is not None and type(value) is not strict_type:
+            errors[key] = [error]
+    if errors:
+        raise m.ValidationError(errors)
+
+
+def _get_strict_type(field_type: Any) -> tuple[type, str] | None:
+    if typing_inspect.is_union_type(field_type) or isinstance(field_type, types.UnionType):
+        type_args = [arg for arg in field_type.__args__ if arg is not types.NoneType]  # noqa
+        if len(type_args) != 1:
+            return None
+        field_type = type_args[0]
+    return _STRICT_TYPES.get(field_type)
+
+
+def _validate_schema(data: dict[str, Any], options: DataclassOptions) -> None:
+    # marshmallow2 does not validate on dump, so dump behaves the same for both versions
+    if is_dump_validation_in_progress():
+        return
+    if options.validate is not None and options.validate(data) is False:
+        raise m.ValidationError("Invalid value.")
+
+
+# post_dump hooks are invoked in alphabetical order too, so it goes after removing None values and formatting
+def _to_positional(data: dict[str, Any], positional_names: Sequence[str] | None) -> Any:
+    if positional_names is None:
+        return data
+    return [data.get(name) for name in positional_names]
+
+
+def _validate_on_dump(obj: Any, dump_validators: Mapping[str, tuple[str, Callable[[Any], Any]]]) -> None:
+    errors: dict[str, Any] = {}
+    for key, (attr, validator) in dump_validators.items():
+        value = getattr(obj, attr, MISSING)
+        if value is MISSING:
+            continue
+        try:
+            if validator(value) is False:
+                errors[key] = ["Invalid value."]
+        except m.ValidationError as e:
+            errors[key] = e.messages
+    if errors:
+        raise m.ValidationError(errors)
+
+
+def _with_dump_default(field: m.fields.Field, metadata: Mapping[str, Any]) -> m.fields.Field:
+    # used on dump when the attribute is absent or MISSING, a load default is not affected
+    if "dump_default" in metadata:
+        set_dump_default(field, metadata["dump_default"])
+    return field
+
+
+def _with_field_flags(field: m.fields.Field, metadata: Mapping[str, Any]) -> m.fields.Field:
+    # a field with a default accepts null unless it is disallowed, the default is still used for an absent key
+    if "allow_none" in metadata:
+        field.allow_none = metadata["allow_none"]
+    if metadata.get("load_only"):
+        field.load_only = True
+    if metadata.get("dump_only"):
+        # a dump only field is absent in loaded data, so the dataclass has to fill it
+        if metadata["default"] is dataclasses.MISSING:
+            raise ValueError("Dump only field must have a default value")
+        field.dump_only = True
+    return field
+
+
+def _as_dump_only(field: m.fields.Field) -> m.fields.Field:
+    # a dump only field is read with getattr, so properties work, and it is skipped on load
+    field.dump_only = True
+    return field
+
+
+def _get_field_default(field: dataclasses.Field[_T], *, all_optional: bool) -> Any:
     default_factory = field.default_factory
     if default_factory is not dataclasses.MISSING:  # type: ignore
         raise ValueError(f"Default factory is not supported for {field}")
+    if all_optional and field.default is dataclasses.MISSING:
+        return MISSING
     return field.default
 
 
@@ -181,12 +646,22 @@ _SIMPLE_TYPE_FIELD_FACTORIES: dict[type, object] = {
     decimal.Decimal: decimal_field,
     int: int_field,
     float: float_field,
+    complex: complex_field,
+    fractions.Fraction: fraction_field,
     uuid.UUID: uuid_field,
     datetime.datetime: datetime_field,
     datetime.date: date_field,
 }
 
 
+_STRICT_TYPES: dict[type, tuple[type, str]] = {
+    bool: (bool, "Not a valid boolean."),
+    str: (str, "Not a valid string."),
+    int: (int, "Not a valid integer."),
+    float: (float, "Not a valid number."),
+}
+
+
 def _get_metadata(*, name: str, default: Any, metadata: Mapping[Any, Any]) -> Mapping[str, Any]:
     result = dict(name=name, default=default)
     result.update({k: v for k, v in metadata.items() if isinstance(k, str)})
@@ -1,12 +1,85 @@
+import contextlib
+import contextvars
 import dataclasses
 import datetime
+import decimal
 import enum
-from typing import Any, Callable, Iterable, Type, cast
+import fractions
+import math
+import pathlib
+import re
+import uuid
+import zoneinfo
+from typing import Any, Callable, Iterable, Iterator, Mapping, Sequence, Type, cast
 
 import marshmallow as m
 import marshmallow.validate
 
 _MARSHMALLOW_VERSION_MAJOR = int(m.__version__.split(".")[0])
+_EPOCH_DATE = datetime.date(1970, 1, 1)
+_ISO_WEEK_DATE_RE = re.compile(r"(\d{4})-W(\d{2})-(\d)")
+_ISO_ORDINAL_DATE_RE = re.compile(r"(\d{4})-(\d{3})")
+_EXTRA_FRACTION_DIGITS_RE = re.compile(r"(\.\d{6})\d+")
+_FRACTION_RE = re.compile(r"(-?\d+)/(\d+)")
+_TZ_NAME_SUFFIX_RE = re.compile(r"(.+)\[([^\[\]]+)\]")
+_ISO_DATETIME_WITH_OFFSET_RE = re.compile(
+    r"(\d{4}-\d{2}-\d{2}[T ]\d{2}:\d{2})(?::(\d{2}))?(?:\.(\d+))?(Z|([+-])(\d{2})(?::?(\d{2}))?)"
+)
+_SPECIAL_DECIMAL_DUMP_ERROR = "Decimal special values are not permitted."
+_BOOL_STRINGS_TRUTHY = frozenset({"true", "1", "yes", "on"})
+_BOOL_STRINGS_FALSY = frozenset({"false", "0", "no", "off"})
+
+
+# marshmallow3 validates dumped data by loading it, so fields and hooks can tell such a load from a regular one
+_is_dump_validation: contextvars.ContextVar[bool] = contextvars.ContextVar("is_dump_validation", default=False)
+
+
+@contextlib.contextmanager
+def dump_validation() -> Iterator[None]:
+    token = _is_dump_validation.set(True)
+    try:
+        yield
+    finally:
+        _is_dump_validation.reset(token)
+
+
+def is_dump_validation_in_progress() -> bool:
+    return _is_dump_validation.get()
+
+
+class TimestampUnit(str, enum.Enum):
+    DAYS = "DAYS"
+    SECONDS = "SECONDS"
+
+    def __str__(self) -> str:
+        return self.value
+
+
+class IntRange(str, enum.Enum):
+    INT32 = "INT32"
+    UINT32 = "UINT32"
+    INT64 = "INT64"
+    UINT64 = "UINT64"
+
+    def __str__(self) -> str:
+        return self.value
+
+
+_INT_RANGE_BOUNDS: dict[IntRange, tuple[int, int]] = {
+    IntRange.INT32: (-(2**31), 2**31 - 1),
+    IntRange.UINT32: (0, 2**32 - 1),
+    IntRange.INT64: (-(2**63), 2**63 - 1),
+    IntRange.UINT64: (0, 2**64 - 1),
+}
+
+
+class UuidFormat(str, enum.Enum):
+    HYPHENATED = "HYPHENATED"
+    SIMPLE = "SIMPLE"
+    URN = "URN"
+
+    def __str__(self) -> str:
+        return self.value
 
 
 def str_field(
@@ -44,12 +117,18 @@ def bool_field(
     required: bool,
     default: Any = dataclasses.MISSING,
     name: str | None = None,
+    truthy: Iterable[Any] | None = None,
+    falsy: Iterable[Any] | None = None,
+    bool_strings: bool = False,
     validate: Callable[[Any], Any] | None = None,
     **_: Any,
 ) -> m.fields.Field:
     if default is m.missing:
-        return m.fields.Bool(
+        return BoolField(
             allow_none=not required,
+            truthy=truthy,
+            falsy=falsy,
+            bool_strings=bool_strings,
             validate=validate,
             **default_fields(m.missing),
             **data_key_fields(name),
@@ -59,10 +138,20 @@ def bool_field(
         if default is None:
             raise ValueError("Default value cannot be none")
 
-        return m.fields.Boolean(required=True, validate=validate, **data_key_fields(name))
+        return BoolField(
+            required=True,
+            truthy=truthy,
+            falsy=falsy,
+            bool_strings=bool_strings,
+            validate=validate,
+            **data_key_fields(name),
+        )
 
-    return m.fields.Bool(
+    return BoolField(
         allow_none=True,
+        truthy=truthy,
+        falsy=falsy,
+        bool_strings=bool_strings,
         validate=validate,
         **default_fields(None if default is dataclasses.MISSING else default),
         **data_key_fields(name),
@@ -74,16 +163,30 @@ def decimal_field(
     required: bool,
     default: Any = dataclasses.MISSING,
     name: str | None = None,
-    places: int = 2,
+    places: int | None = 2,
+    dump_places: int | None = None,
     as_string: bool = True,
+    thousands_separator: str | None = None,
+    allow_nan: bool = False,
+    normalize: bool = False,
+    scientific: bool = False,
+    tag: str | None = None,
+    none_as_zero: bool = False,
     validate: Callable[[Any], Any] | None = None,
     **_: Any,
 ) -> m.fields.Field:
     if default is m.missing:
-        return m.fields.Decimal(
+        return DecimalField(
             allow_none=not required,
             as_string=as_string,
             places=places,
+            dump_places=dump_places,
+            thousands_separator=thousands_separator,
+            allow_nan=allow_nan,
+            normalize=normalize,
+            scientific=scientific,
+            tag=tag,
+            none_as_zero=none_as_zero,
             validate=validate,
             **default_fields(m.missing),
             **data_key_fields(name),
@@ -92,14 +195,32 @@ def decimal_field(
     if required:
         if default is None:
             raise ValueError("Default value cannot be none")
-        return m.fields.Decimal(
-            required=True, as_string=as_string, places=places, validate=validate, **data_key_fields(name)
+        return DecimalField(
+            required=True,
+            as_string=as_string,
+            places=places,
+            dump_places=dump_places,
+            thousands_separator=thousands_separator,
+            allow_nan=allow_nan,
+            normalize=normalize,
+            scientific=scientific,
+            tag=tag,
+            none_as_zero=none_as_zero,
+            validate=validate,
+            **data_key_fields(name),
         )
 
-    return m.fields.Decimal(
+    return DecimalField(
         allow_none=True,
         as_string=as_string,
         places=places,
+        dump_places=dump_places,
+        thousands_separator=thousands_separator,
+        allow_nan=allow_nan,
+        normalize=normalize,
+        scientific=scientific,
+        tag=tag,
+        none_as_zero=none_as_zero,
         validate=validate,
         **default_fields(None if default is dataclasses.MISSING else default),
         **data_key_fields(name),
@@ -111,12 +232,16 @@ def int_field(
     required: bool,
     default: Any = dataclasses.MISSING,
     name: str | None = None,
+    thousands_separator: str | None = None,
+    int_range: IntRange | None = None,
     validate: Callable[[Any], Any] | None = None,
     **_: Any,
 ) -> m.fields.Field:
     if default is m.missing:
-        return m.fields.Int(
+        return IntField(
             allow_none=not required,
+            thousands_separator=thousands_separator,
+            int_range=int_range,
             validate=validate,
             **default_fields(m.missing),
             **data_key_fields(name),
@@ -125,10 +250,18 @@ def int_field(
     if required:
         if default is None:
             raise ValueError("Default value cannot be none")
-        return m.fields.Int(required=True, validate=validate, **data_key_fields(name))
+        return IntField(
+            required=True,
+            thousands_separator=thousands_separator,
+            int_range=int_range,
+            validate=validate,
+            **data_key_fields(name),
+        )
 
-    return m.fields.Int(
+    return IntField(
         allow_none=True,
+        thousands_separator=thousands_separator,
+        int_range=int_range,
         validate=validate,
         **default_fields(None if default is dataclasses.MISSING else default),
         **data_key_fields(name),
@@ -140,12 +273,22 @@ def float_field(
     required: bool,
     default: Any = dataclasses.MISSING,
     name: str | None = None,
+    places: int | None = None,
+    allow_nan: bool = False,
+    thousands_separator: str | None = None,
+    allow_precision_loss: bool = True,
+    none_as_zero: bool = False,
     validate: Callable[[Any], Any] | None = None,
     **_: Any,
 ) -> m.fields.Field:
     if default is m.missing:
-        return m.fields.Float(
+        return FloatField(
             allow_none=not required,
+            places=places,
+            allow_nan=allow_nan,
+            thousands_separator=thousands_separator,
+            allow_precision_loss=allow_precision_loss,
+            none_as_zero=none_as_zero,
             validate=validate,
             **default_fields(m.missing),
             **data_key_fields(name),
@@ -154,10 +297,24 @@ def float_field(
     if required:
         if default is None:
             raise ValueError("Default value cannot be none")
-        return m.fields.Float(required=True, validate=validate, **data_key_fields(name))
+        return FloatField(
+            required=True,
+            places=places,
+            allow_nan=allow_nan,
+            thousands_separator=thousands_separator,
+            allow_precision_loss=allow_precision_loss,
+            none_as_zero=none_as_zero,
+            validate=validate,
+            **data_key_fields(name),
+        )
 
-    return m.fields.Float(
+    return FloatField(
         allow_none=True,
+        places=places,
+        allow_nan=allow_nan,
+        thousands_separator=thousands_separator,
+        allow_precision_loss=allow_precision_loss,
+        none_as_zero=none_as_zero,
         validate=validate,
         **default_fields(None if default is dataclasses.MISSING else default),
         **data_key_fields(name),
@@ -169,12 +326,18 @@ def uuid_field(
     required: bool,
     default: Any = dataclasses.MISSING,
     name: str | None = None,
+    uuid_format: UuidFormat = UuidFormat.HYPHENATED,
+    uppercase: bool = False,
+    version: int | None = None,
     validate: Callable[[Any], Any] | None = None,
     **_: Any,
 ) -> m.fields.Field:
     if default is m.missing:
-        return m.fields.UUID(
+        return UuidField(
             allow_none=not required,
+            uuid_format=uuid_format,
+            uppercase=uppercase,
+            version=version,
             validate=validate,
             **default_fields(m.missing),
             **data_key_fields(name),
@@ -183,10 +346,20 @@ def uuid_field(
     if required:
         if default is None:
             raise ValueError("Default value cannot be none")
-        return m.fields.UUID(required=True, validate=validate, **data_key_fields(name))
+        return UuidField(
+            required=True,
+            uuid_format=uuid_format,
+            uppercase=uppercase,
+            version=version,
+            validate=validate,
+            **data_key_fields(name),
+        )
 
-    return m.fields.UUID(
+    return UuidField(
         allow_none=True,
+        uuid_format=uuid_format,
+        uppercase=uppercase,
+        version=version,
         validate=validate,
         **default_fields(None if default is dataclasses.MISSING else default),
         **data_key_fields(name),
@@ -198,12 +371,22 @@ def datetime_field(
     required: bool,
     default: Any = dataclasses.MISSING,
     name: str | None = None,
+    formats: Sequence[str] | None = None,
+    require_tz: bool = False,
+    require_naive: bool = False,
+    default_tz: datetime.tzinfo | None = None,
+    tz_name: bool = False,
     validate: Callable[[Any], Any] | None = None,
     **_: Any,
 ) -> m.fields.Field:
     if default is m.missing:
         return DateTimeField(
             allow_none=not required,
+            formats=formats,
+            require_tz=require_tz,
+            require_naive=require_naive,
+            default_tz=default_tz,
+            tz_name=tz_name,
             validate=validate,
             **default_fields(m.missing),
             **data_key_fields(name),
@@ -212,10 +395,24 @@ def datetime_field(
     if required:
         if default is None:
             raise ValueError("Default value cannot be none")
-        return DateTimeField(required=True, validate=validate, **data_key_fields(name))
+        return DateTimeField(
+            required=True,
+            formats=formats,
+            require_tz=require_tz,
+            require_naive=require_naive,
+            default_tz=default_tz,
+            tz_name=tz_name,
+            validate=validate,
+            **data_key_fields(name),
+        )
 
     return DateTimeField(
         allow_none=True,
+        formats=formats,
+        require_tz=require_tz,
+        require_naive=require_naive,
+        default_tz=default_tz,
+        tz_name=tz_name,
         validate=validate,
         **default_fields(None if default is dataclasses.MISSING else default),
         **data_key_fields(name),
@@ -223,6 +420,110 @@ def datetime_field(
 
 
 def date_field(
+    *,
+    required: bool,
+    default: Any = dataclasses.MISSING,
+    name: str | None = None,
+    timestamp_unit: TimestampUnit | None = None,
+    iso_week_and_ordinal: bool = False,
+    accept_datetime: bool = False,
+    validate: Callable[[Any], Any] | None = None,
+    **_: Any,
+) -> m.fields.Field:
+    if default is m.missing:
+        return DateField(
+            allow_none=not required,
+            timestamp_unit=timestamp_unit,
+            iso_week_and_ordinal=iso_week_and_ordinal,
+            accept_datetime=accept_datetime,
+            validate=validate,
+            **default_fields(m.missing),
+            **data_key_fields(name),
+        )
+
+    if required:
+        if default is None:
+            raise ValueError("Default value cannot be none")
+        return DateField(
+            required=True,
+            timestamp_unit=timestamp_unit,
+            iso_week_and_ordinal=iso_week_and_ordinal,
+            accept_datetime=accept_datetime,
+            validate=validate,
+            **data_key_fields(name),
+        )
+
+    return DateField(
+        allow_none=True,
+        timestamp_unit=timestamp_unit,
+        iso_week_and_ordinal=iso_week_and_ordinal,
+        accept_datetime=accept_datetime,
+        validate=validate,
+        **default_fields(None if default is dataclasses.MISSING else default),
+        **data_key_fields(name),
+    )
+
+
+def complex_field(
+    *,
+    required: bool,
+    default: Any = dataclasses.MISSING,
+    name: str | None = None,
+    validate: Callable[[Any], Any] | None = None,
+    **_: Any,
+) -> m.fields.Field:
+    if default is m.missing:
+        return ComplexField(
+            allow_none=not required,
+            validate=validate,
+            **default_fields(m.missing),
+            **data_key_fields(name),
+        )
+
+    if required:
+        if default is None:
+            raise ValueError("Default value cannot be none")
+        return ComplexField(required=True, validate=validate, **data_key_fields(name))
+
+    return ComplexField(
+        allow_none=True,
+        validate=validate,
+        **default_fields(None if default is dataclasses.MISSING else default),
+        **data_key_fields(name),
+    )
+
+
+def fraction_field(
+    *,
+    required: bool,
+    default: Any = dataclasses.MISSING,
+    name: str | None = None,
+    validate: Callable[[Any], Any] | None = None,
+    **_: Any,
+) -> m.fields.Field:
+    if default is m.missing:
+        return FractionField(
+            allow_none=not required,
+            validate=validate,
+            **default_fields(m.missing),
+            **data_key_fields(name),
+        )
+
+    if required:
+        if default is None:
+            raise ValueError("Default value cannot be none")
+        return FractionField(required=True, validate=validate, **data_key_fields(name))
+
+    return FractionField(
+        allow_none=True,
+        validate=validate,
+        **default_fields(None if default is dataclasses.MISSING else default),
+        **data_key_fields(name),
+    )
+
+
+def path_field(
+    path_type: Type[pathlib.PurePath],
     *,
     required: bool,
     default: Any = dataclasses.MISSING,
@@ -231,7 +532,8 @@ def date_field(
     **_: Any,
 ) -> m.fields.Field:
     if default is m.missing:
-        return m.fields.Date(
+        return PathField(
+            path_type=path_type,
             allow_none=not required,
             validate=validate,
             **default_fields(m.missing),
@@ -241,9 +543,51 @@ def date_field(
     if required:
         if default is None:
             raise ValueError("Default value cannot be none")
-        return m.fields.Date(required=True, validate=validate, **data_key_fields(name))
+        return PathField(path_type=path_type, required=True, validate=validate, **data_key_fields(name))
 
-    return m.fields.Date(
+    return PathField(
+        path_type=path_type,
+        allow_none=True,
+        validate=validate,
+        **default_fields(None if default is dataclasses.MISSING else default),
+        **data_key_fields(name),
+    )
+
+
+def custom_field(
+    *,
+    required: bool,
+    serializer: Callable[[Any], Any],
+    deserializer: Callable[[Any], Any],
+    default: Any = dataclasses.MISSING,
+    name: str | None = None,
+    validate: Callable[[Any], Any] | None = None,
+    **_: Any,
+) -> m.fields.Field:
+    if default is m.missing:
+        return CustomField(
+            serializer=serializer,
+            deserializer=deserializer,
+            allow_none=not required,
+            validate=validate,
+            **default_fields(m.missing),
+            **data_key_fields(name),
+        )
+
+    if required:
+        if default is None:
+            raise ValueError("Default value cannot be none")
+        return CustomField(
+            serializer=serializer,
+            deserializer=deserializer,
+            required=True,
+            validate=validate,
+            **data_key_fields(name),
+        )
+
+    return CustomField(
+        serializer=serializer,
+        deserializer=deserializer,
         allow_none=True,
         validate=validate,
         **default_fields(None if default is dataclasses.MISSING else default),
@@ -294,15 +638,15 @@ def list_field(
     default: Any = dataclasses.MISSING,
     name: str | None = None,
     validate: Callable[[Any], Any] | None = None,
+    max_items: int | None = None,
     **_: Any,
 ) -> m.fields.Field:
-    if validate is not None:
-        raise ValueError("Validation is not supported")
-
     if default is m.missing:
-        return m.fields.List(
+        return ListField(
             field,
             allow_none=not required,
+            validate=validate,
+            max_items=max_items,
             **default_fields(m.missing),
             **data_key_fields(name),
         )
@@ -310,14 +654,16 @@ def list_field(
     if required:
         if default is None:
             raise ValueError("Default value cannot be none")
-        return m.fields.List(field, required=True, **data_key_fields(name))
+        return ListField(field, required=True, validate=validate, max_items=max_items, **data_key_fields(name))
 
     if default is not dataclasses.MISSING and default is not None:
         raise ValueError("Default value is not supported for list field")
 
-    return m.fields.List(
+    return ListField(
         field,
         allow_none=True,
+        validate=validate,
+        max_items=max_items,
         **default_fields(None),
         **data_key_fields(name),
     )
@@ -328,22 +674,48 @@ def dict_field(
     required: bool,
     default: Any = dataclasses.MISSING,
     name: str | None = None,
+    key_field: m.fields.Field | None = None,
+    value_field: m.fields.Field | None = None,
     validate: Callable[[Any], Any] | None = None,
+    max_keys: int | None = None,
+    entries: bool = False,
     **_: Any,
 ) -> m.fields.Field:
-    if validate is not None:
-        raise ValueError("Validation is not supported")
+    if default is m.missing:
+        return DictField(
+            allow_none=not required,
+            key_field=key_field,
+            value_field=value_field,
+            validate=validate,
+            max_keys=max_keys,
+            entries=entries,
+            **default_fields(m.missing),
+            **data_key_fields(name),
+        )
 
     if required:
         if default is None:
             raise ValueError("Default value cannot be none")
-        return m.fields.Dict(required=True, **data_key_fields(name))
+        return DictField(
+            required=True,
+            key_field=key_field,
+            value_field=value_field,
+            validate=validate,
+            max_keys=max_keys,
+            entries=entries,
+            **data_key_fields(name),
+        )
 
     if default is not dataclasses.MISSING and default is not None:
         raise ValueError("Default value is not supported for dict field")
 
-    return m.fields.Dict(
+    return DictField(
         allow_none=True,
+        key_field=key_field,
+        value_field=value_field,
+        validate=validate,
+        max_keys=max_keys,
+        entries=entries,
         **default_fields(None),
         **data_key_fields(name),
     )
@@ -355,11 +727,14 @@ def enum_field(
     required: bool,
     name: str | None = None,
     default: Any = dataclasses.MISSING,
+    aliases: Mapping[str, enum.Enum] | None = None,
     validate: Callable[[Any], Any] | None = None,
+    **_: Any,
 ) -> marshmallow.fields.Field:
     if default is m.missing:
         return EnumField(
             enum_type=enum_type,
+            aliases=aliases,
             allow_none=not required,
             **default_fields(m.missing),
             **data_key_fields(name),
@@ -373,12 +748,14 @@ def enum_field(
             raise ValueError("Default value cannot be none")
         return EnumField(
             enum_type=enum_type,
+            aliases=aliases,
             required=True,
             **data_key_fields(name),
         )
 
     return EnumField(
         enum_type=enum_type,
+        aliases=aliases,
         allow_none=True,
         **default_fields(None if default is dataclasses.MISSING else default),
         **data_key_fields(name),
@@ -400,6 +777,590 @@ def raw_field(
     )
 
 
+class BoolField(m.fields.Boolean):
+    def __init__(
+        self,
+        *args: Any,
+        truthy: Iterable[Any] | None = None,
+        falsy: Iterable[Any] | None = None,
+        bool_strings: bool = False,
+        **kwargs: Any,
+    ):
+        """
+        :param truthy: values which are loaded as True, strings are matched case-insensitively
+        :param falsy: values which are loaded as False, strings are matched case-insensitively
+        :param bool_strings: if set, booleans and strings true/1/yes/on and false/0/no/off in any case are accepted,
+            truthy and falsy take precedence over them
+        """
+        super().__init__(*args, **kwargs)
+        self.case_insensitive = bool_strings or truthy is not None or falsy is not None
+        # marshmallow2 has no truthy and falsy arguments, so they are assigned to the instance for both versions
+        if bool_strings:
+            self.truthy = {True, *_BOOL_STRINGS_TRUTHY}
+            self.falsy = {False, *_BOOL_STRINGS_FALSY}
+        if truthy is not None:
+            self.truthy = set(truthy)
+        if falsy is not None:
+            self.falsy = set(falsy)
+        if self.case_insensitive:
+            self.truthy = {_lower_if_str(value) for value in self.truthy}
+            self.falsy = {_lower_if_str(value) for value in self.falsy}
+
+    def _deserialize(self, value: Any, attr: Any, data: Any, **kwargs: Any) -> Any:
+        if self.case_insensitive:
+            value = _lower_if_str(value)
+        return super()._deserialize(value, attr, data, **kwargs)
+
+
+class IntField(m.fields.Int):
+    def __init__(
+        self,
+        *args: Any,
+        thousands_separator: str | None = None,
+        int_range: IntRange | None = None,
+        **kwargs: Any,
+    ):
+        """
+        :param thousands_separator: if set, strings with well-formed digit groups are accepted on load
+        :param int_range: if set, integers out of bounds of this fixed-width integer type are rejected on load
+        """
+        super().__init__(*args, **kwargs)
+        self.thousands_separator = _validate_thousands_separator(thousands_separator)
+        self.int_range = int_range
+
+    def _deserialize(self, value: Any, attr: Any, data: Any, **kwargs: Any) -> Any:
+        value = _remove_thousands_separator(value, self.thousands_separator)
+        result = super()._deserialize(value, attr, data, **kwargs)
+        if self.int_range is not None and isinstance(result, int):
+            min_value, max_value = _INT_RANGE_BOUNDS[self.int_range]
+            if not min_value <= result <= max_value:
+                raise m.ValidationError(f"Integer out of range for {self.int_range.value.lower()}.")
+        return result
+
+
+class DecimalField(m.fields.Decimal):
+    def __init__(
+        self,
+        *args: Any,
+        dump_places: int | None = None,
+        thousands_separator: str | None = None,
+        normalize: bool = False,
+        scientific: bool = False,
+        tag: str | None = None,
+        none_as_zero: bool = False,
+        **kwargs: Any,
+    ):
+        """
+        :param dump_places: if set, decimals are quantized to it on dump instead of places,
+            so with places=None loaded values keep full precision and dumped ones have a fixed scale
+        :param thousands_separator: if set, strings with well-formed digit groups are accepted on load
+        :param normalize: if set, trailing zeros are stripped on dump after quantizing to places
+        :param scientific: if set, decimals are dumped as strings in E notation keeping all digits
+        :param tag: if set, objects like {tag: "1.23"} are accepted on load as well
+        :param none_as_zero: if set, null is loaded as zero unless the field is optional
+        """
+        super().__init__(*args, **kwargs)
+        self.dump_places = dump_places
+        self.thousands_separator = _validate_thousands_separator(thousands_separator)
+        self.normalize = normalize
+        self.scientific = scientific
+        self.tag = tag
+        self.none_as_zero = none_as_zero
+
+    def deserialize(self, value: Any, *args: Any, **kwargs: Any) -> Any:
+        if value is None and self.none_as_zero and not self.allow_none:
+            value = 0
+        return super().deserialize(value, *args, **kwargs)
+
+    def _serialize(self, value: Any, attr: Any, obj: Any, **kwargs: Any) -> Any:
+        if self.dump_places is not None and isinstance(value, decimal.Decimal) and value.is_finite():
+            # quantized here as marshmallow would quantize it to places once again
+            value = value.quantize(decimal.Decimal(1).scaleb(-self.dump_places), rounding=self.rounding)
+            return self._to_string(value) if self.as_string else value
+        if self.normalize and isinstance(value, decimal.Decimal) and value.is_finite():
+            # quantized to places first, otherwise marshmallow would put trailing zeros back
+            if self.places is not None:
+                value = value.quantize(self.places, rounding=self.rounding)
+            value = _normalize_decimal(value)
+            return self._to_string(value) if self.as_string else value
+        if not self.allow_nan and isinstance(value, decimal.Decimal) and not value.is_finite():
+            if _MARSHMALLOW_VERSION_MAJOR < 3:
+                raise m.ValidationError(_SPECIAL_DECIMAL_DUMP_ERROR)
+            # marshmallow3 validates a dumped value by loading it, so it is reported there
+            return str(value)
+        return super()._serialize(value, attr, obj, **kwargs)
+
+    def _deserialize(self, value: Any, attr: Any, data: Any, **kwargs: Any) -> Any:
+        if self.tag is not None and isinstance(value, Mapping) and list(value) == [self.tag]:
+            value = value[self.tag]
+        value = _remove_thousands_separator(value, self.thousands_separator)
+        # a special value is dumped as a string to be reported by validation, marshmallow's message is kept on load
+        if is_dump_validation_in_progress() and not self.allow_nan and _is_special_decimal_string(value):
+            raise m.ValidationError(_SPECIAL_DECIMAL_DUMP_ERROR)
+        return super()._deserialize(value, attr, data, **kwargs)
+
+    def _to_string(self, value: Any) -> str:
+        if self.scientific and value.is_finite():
+            return format(value, "E")
+        return super()._to_string(value)
+
+
+class FloatField(m.fields.Float):
+    def __init__(
+        self,
+        *args: Any,
+        places: int | None = None,
+        allow_nan: bool = False,
+        thousands_separator: str | None = None,
+        allow_precision_loss: bool = True,
+        none_as_zero: bool = False,
+        **kwargs: Any,
+    ):
+        """
+        :param allow_precision_loss: if not set, integers which cannot be represented as a float exactly are rejected
+        :param none_as_zero: if set, null is loaded as zero unless the field is optional
+        """
+        super().__init__(*args, **kwargs)
+        self.places = places
+        self.allow_nan = allow_nan
+        self.thousands_separator = _validate_thousands_separator(thousands_separator)
+        self.allow_precision_loss = allow_precision_loss
+        self.none_as_zero = none_as_zero
+
+    def deserialize(self, value: Any, *args: Any, **kwargs: Any) -> Any:
+        if value is None and self.none_as_zero and not self.allow_none:
+            value = 0
+        return super().deserialize(value, *args, **kwargs)
+
+    def _deserialize(self, value: Any, attr: Any, data: Any, **kwargs: Any) -> Any:
+        if not self.allow_precision_loss and _is_inexact_float(value):
+            raise m.ValidationError("Integer too large for float field.")
+        value = _remove_thousands_separator(value, self.thousands_separator)
+        result = super()._deserialize(value, attr, data, **kwargs)
+        # marshmallow2 has no allow_nan, so special values are rejected here for both versions
+        if not self.allow_nan and isinstance(result, float) and not math.isfinite(result):
+            raise m.ValidationError("Special numeric values (nan or infinity) are not permitted.")
+        return result
+
+    def _serialize(self, value: Any, attr: Any, obj: Any, **kwargs: Any) -> Any:
+        if self.places is not None and isinstance(value, float) and math.isfinite(value):
+            # round half to even, the same way as decimal fields are quantized
+            value = float(
+                decimal.Decimal(str(value)).quantize(decimal.Decimal(1).scaleb(-self.places), decimal.ROUND_HALF_EVEN)
+            )
+        return super()._serialize(value, attr, obj, **kwargs)
+
+
+class DateField(m.fields.Date):
+    def __init__(
+        self,
+        *args: Any,
+        timestamp_unit: TimestampUnit | None = None,
+        iso_week_and_ordinal: bool = False,
+        accept_datetime: bool = False,
+        **kwargs: Any,
+    ):
+        """
+        :param timestamp_unit: if set, numbers are accepted on load as an epoch timestamp in this unit
+        :param iso_week_and_ordinal: if set, ISO 8601 week dates (2024-W05-3) and ordinal dates (2024-123)
+            are accepted on load as well
+        :param accept_datetime: if set, datetime objects are accepted on load and their time is dropped
+        """
+        super().__init__(*args, **kwargs)
+        self.timestamp_unit = timestamp_unit
+        self.iso_week_and_ordinal = iso_week_and_ordinal
+        self.accept_datetime = accept_datetime
+
+    def _serialize(self, value: Any, attr: Any, obj: Any, **kwargs: Any) -> Any:
+        # datetime is a subclass of date, so its time is dropped
+        if isinstance(value, datetime.datetime):
+            value = value.date()
+        return super()._serialize(value, attr, obj, **kwargs)
+
+    def _deserialize(self, value: Any, attr: Any, data: Any, **kwargs: Any) -> Any:
+        if self.accept_datetime and isinstance(value, datetime.datetime):
+            return value.date()
+        if self.timestamp_unit is not None and isinstance(value, (int, float)) and not isinstance(value, bool):
+            try:
+                if self.timestamp_unit == TimestampUnit.DAYS:
+                    return _EPOCH_DATE + datetime.timedelta(days=value)
+                return datetime.datetime.fromtimestamp(value, datetime.timezone.utc).date()
+            except (OverflowError, OSError, ValueError):
+                raise m.ValidationError("Not a valid date.")
+        if self.iso_week_and_ordinal and isinstance(value, str):
+            if (parsed := _parse_iso_week_or_ordinal_date(value)) is not None:
+                return parsed
+        return super()._deserialize(value, attr, data, **kwargs)
+
+
+class UuidField(m.fields.UUID):
+    def __init__(
+        self,
+        *args: Any,
+        uuid_format: UuidFormat = UuidFormat.HYPHENATED,
+        uppercase: bool = False,
+        version: int | None = None,
+        **kwargs: Any,
+    ):
+        """
+        :param uuid_format: a format of dumped values, any of them is accepted on load
+        :param uppercase: if set, hex digits of dumped values are uppercase
+        :param version: if set, UUIDs of other versions are rejected on load and dump
+        """
+        super().__init__(*args, **kwargs)
+        self.uuid_format = uuid_format
+        self.uppercase = uppercase
+        self.version = version
+
+    def _deserialize(self, value: Any, attr: Any, data: Any, **kwargs: Any) -> Any:
+        result = super()._deserialize(value, attr, data, **kwargs)
+        self._check_version(result)
+        return result
+
+    def _serialize(self, value: Any, attr: Any, obj: Any, **kwargs: Any) -> Any:
+        # marshmallow3 validates a dumped value by loading it, so it is reported there
+        if _MARSHMALLOW_VERSION_MAJOR < 3 and isinstance(value, uuid.UUID):
+            self._check_version(value)
+        if not isinstance(value, uuid.UUID) or (self.uuid_format == UuidFormat.HYPHENATED and not self.uppercase):
+            return super()._serialize(value, attr, obj, **kwargs)
+        if self.uuid_format == UuidFormat.SIMPLE:
+            serialized = value.hex
+        else:
+            serialized = str(value)
+        if self.uppercase:
+            serialized = serialized.upper()
+        if self.uuid_format == UuidFormat.URN:
+            serialized = f"urn:uuid:{serialized}"
+        return serialized
+
+    def _check_version(self, value: uuid.UUID) -> None:
+        if self.version is not None and value.version != self.version:
+            raise m.ValidationError(f"Expected UUID version {self.version}.")
+
+
+class PathField(m.fields.String):
+    default_error = "Not a valid path."
+
+    def __init__(self, *args: Any, path_type: Type[pathlib.PurePath], **kwargs: Any):
+        super().__init__(*args, **kwargs)
+        self.path_type = path_type
+
+    def _serialize(self, value: Any, attr: Any, obj: Any, **kwargs: Any) -> Any:
+        if value is None:
+            return None
+        if isinstance(value, pathlib.PurePath):
+            return str(value)
+        # marshmallow3 validates a dumped value by loading it, so it is reported there
+        if _MARSHMALLOW_VERSION_MAJOR >= 3:
+            return value
+        raise m.ValidationError(self.default_error)
+
+    def _deserialize(self, value: Any, attr: Any, data: Any, **kwargs: Any) -> Any:
+        if not isinstance(value, str):
+            raise m.ValidationError(self.default_error)
+        return self.path_type(super()._deserialize(value, attr, data, **kwargs))
+
+
+class ComplexField(m.fields.Field):
+    default_error = "Not a valid complex number."
+
+    def _serialize(self, value: Any, attr: Any, obj: Any, **kwargs: Any) -> Any:
+        if value is None:
+            return None
+        if isinstance(value, complex):
+            return {"real": value.real, "imag": value.imag}
+        # marshmallow3 validates a dumped value by loading it, so it is reported there
+        if _MARSHMALLOW_VERSION_MAJOR >= 3:
+            return value
+        raise m.ValidationError(self.default_error)
+
+    def _deserialize(self, value: Any, attr: Any, data: Any, **kwargs: Any) -> Any:
+        if not isinstance(value, dict):
+            raise m.ValidationError(self.default_error)
+        real = value.get("real")
+        imag = value.get("imag")
+        for part in (real, imag):
+            if isinstance(part, bool) or not isinstance(part, (int, float)):
+                raise m.ValidationError(self.default_error)
+        return complex(real, imag)
+
+
+class FractionField(m.fields.Field):
+    default_error = "Not a valid fraction."
+
+    def _serialize(self, value: Any, attr: Any, obj: Any, **kwargs: Any) -> Any:
+        if value is None:
+            return None
+        if isinstance(value, fractions.Fraction):
+            return f"{value.numerator}/{value.denominator}"
+        # marshmallow3 validates a dumped value by loading it, so it is reported there
+        if _MARSHMALLOW_VERSION_MAJOR >= 3:
+            return value
+        raise m.ValidationError(self.default_error)
+
+    def _deserialize(self, value: Any, attr: Any, data: Any, **kwargs: Any) -> Any:
+        if not isinstance(value, str) or (match := _FRACTION_RE.fullmatch(value)) is None:
+            raise m.ValidationError(self.default_error)
+        numerator, denominator = match.groups()
+        if int(denominator) == 0:
+            raise m.ValidationError(self.default_error)
+        return fractions.Fraction(int(numerator), int(denominator))
+
+
+class CustomField(m.fields.Field):
+    default_error = "Invalid value."
+
+    def __init__(
+        self,
+        *args: Any,
+        serializer: Callable[[Any], Any],
+        deserializer: Callable[[Any], Any],
+        **kwargs: Any,
+    ):
+        """
+        :param serializer: a callable which converts a value to a JSON-compatible one on dump
+        :param deserializer: a callable which converts a JSON-compatible value on load,
+            its ValueError and TypeError are reported as errors of the field
+        """
+        super().__init__(*args, **kwargs)
+        self.serializer = serializer
+        self.deserializer = deserializer
+
+    def _serialize(self, value: Any, attr: Any, obj: Any, **kwargs: Any) -> Any:
+        if value is None:
+            return None
+        return self.serializer(value)
+
+    def _deserialize(self, value: Any, attr: Any, data: Any, **kwargs: Any) -> Any:
+        try:
+            return self.deserializer(value)
+        except (ValueError, TypeError):
+            raise m.ValidationError(self.default_error)
+
+
+class ListField(m.fields.List):
+    def __init__(self, *args: Any, max_items: int | None = None, **kwargs: Any):
+        """
+        :param max_items: if set, a collection with more items is rejected on load before its items are loaded
+        """
+        super().__init__(*args, **kwargs)
+        self.max_items = max_items
+
+    def _deserialize(self, value: Any, attr: Any, data: Any, **kwargs: Any) -> Any:
+        if self.max_items is not None and isinstance(value, (list, tuple)) and len(value) > self.max_items:
+            raise m.ValidationError(f"Too many items (limit {self.max_items}).")
+        return super()._deserialize(value, attr, data, **kwargs)
+
+
+class DictField(m.fields.Dict):
+    def __init__(
+        self,
+        *args: Any,
+        key_field: m.fields.Field | None = None,
+        value_field: m.fields.Field | None = None,
+        max_keys: int | None = None,
+        entries: bool = False,
+        **kwargs: Any,
+    ):
+        """
+        :param key_field: a field to load and dump keys, keys are taken as is if not set,
+            dumped keys are converted to strings like json.dumps does
+        :param value_field: a field to load and dump values, values are taken as is if not set
+        :param max_keys: if set, a mapping with more keys is rejected on load before its items are loaded
+        :param entries: if set, a mapping is dumped as an array of [key, value] pairs keeping keys as they are dumped,
+            such an array is accepted on load as well as an object
+        """
+        super().__init__(*args, **kwargs)
+        self.key_field = key_field
+        self.value_field = value_field
+        self.max_keys = max_keys
+        self.entries = entries
+
+    def _serialize(self, value: Any, attr: Any, obj: Any, **kwargs: Any) -> Any:
+        # any mapping, e.g. OrderedDict, defaultdict or a custom one, is dumped as a plain dict
+        if not isinstance(value, Mapping):
+            return super()._serialize(value, attr, obj, **kwargs)
+        if self.entries:
+            return [
+                [self._serialize_item(self.key_field, key), self._serialize_item(self.value_field, item)]
+                for key, item in value.items()
+            ]
+        return {self._serialize_key(key): self._serialize_item(self.value_field, item) for key, item in value.items()}
+
+    def _deserialize(self, value: Any, attr: Any, data: Any, **kwargs: Any) -> Any:
+        is_entries = self.entries and isinstance(value, (list, tuple))
+        if self.max_keys is not None and (is_entries or isinstance(value, Mapping)) and len(value) > self.max_keys:
+            raise m.ValidationError(f"Too many keys (limit {self.max_keys}).")
+        if is_entries:
+            return self._deserialize_entries(value)
+        if self.key_field is None and self.value_field is None:
+            return super()._deserialize(value, attr, data, **kwargs)
+        if not isinstance(value, Mapping):
+            raise m.ValidationError("Not a valid mapping type.")
+        result: dict[Any, Any] = {}
+        errors: dict[Any, Any] = {}
+        for key, item in value.items():
+            try:
+                result[self._deserialize_item(self.key_field, key)] = self._deserialize_item(self.value_field, item)
+            except m.ValidationError as e:
+                errors[key] = e.messages
+        if errors:
+            raise m.ValidationError(errors)
+        return result
+
+    def _deserialize_entries(self, value: Sequence[Any]) -> dict[Any, Any]:
+        result: dict[Any, Any] = {}
+        errors: dict[Any, Any] = {}
+        for index, entry in enumerate(value):
+            if not isinstance(entry, (list, tuple)) or len(entry) != 2:
+                errors[index] = ["Not a valid entry."]
+                continue
+            key, item = entry
+            try:
+                result[self._deserialize_item(self.key_field, key)] = self._deserialize_item(self.value_field, item)
+            except m.ValidationError as e:
+                errors[index] = e.messages
+            except TypeError:
+                # a key which is taken as is can be unhashable, e.g. a list
+                errors[index] = ["Not a valid entry."]
+        if errors:
+            raise m.ValidationError(errors)
+        return result
+
+    def _serialize_key(self, key: Any) -> Any:
+        if self.key_field is None:
+            return key
+        serialized = self.key_field._serialize(key, None, None)
+        return serialized if isinstance(serialized, str) else str(serialized)
+
+    @staticmethod
+    def _serialize_item(field: m.fields.Field | None, value: Any) -> Any:
+        return value if field is None else field._serialize(value, None, None)
+
+    @staticmethod
+    def _deserialize_item(field: m.fields.Field | None, value: Any) -> Any:
+        return value if field is None else field.deserialize(value)
+
+
+def _is_inexact_float(value: Any) -> bool:
+    if isinstance(value, bool) or not isinstance(value, int):
+        return False
+    try:
+        return float(value) != value
+    except OverflowError:
+        return True
+
+
+def _is_special_decimal_string(value: Any) -> bool:
+    if not isinstance(value, str):
+        return False
+    try:
+        return not decimal.Decimal(value).is_finite()
+    except decimal.InvalidOperation:
+        return False
+
+
+def _normalize_decimal(value: decimal.Decimal) -> decimal.Decimal:
+    # the precision is enough to keep all digits, the exponent form like 1E+2 is dumped as 100 by the fixed-point format
+    return value.normalize(decimal.Context(prec=max(len(value.as_tuple().digits), 1)))
+
+
+def _validate_thousands_separator(separator: str | None) -> str | None:
+    if separator is not None and (not separator or separator.isdigit() or "." in separator):
+        raise ValueError(f"Invalid thousands separator {separator!r}")
+    return separator
+
+
+def _lower_if_str(value: Any) -> Any:
+    return value.lower() if isinstance(value, str) else value
+
+
+def _remove_thousands_separator(value: Any, separator: str | None) -> Any:
+    if separator is None or not isinstance(value, str) or separator not in value:
+        return value
+    integer_part, _, fractional_part = value.partition(".")
+    groups = integer_part.lstrip("+-").split(separator)
+    # a malformed grouping like 1,2,3 is left as is, so it fails to parse
+    if separator in fractional_part or not 1 <= len(groups[0]) <= 3 or any(len(group) != 3 for group in groups[1:]):
+        return value
+    return value.replace(separator, "")
+
+
+def _parse_iso_week_or_ordinal_date(value: str) -> datetime.date | None:
+    try:
+        if match := _ISO_WEEK_DATE_RE.fullmatch(value):
+            year, week, weekday = match.groups()
+            return datetime.date.fromisocalendar(int(year), int(week), int(weekday))
+        if match := _ISO_ORDINAL_DATE_RE.fullmatch(value):
+            year, day = match.groups()
+            parsed = datetime.date(int(year), 1, 1) + datetime.timedelta(days=int(day) - 1)
+            if int(day) < 1 or parsed.year != int(year):
+                raise m.ValidationError("Not a valid date.")
+            return parsed
+    except (OverflowError, ValueError):
+        raise m.ValidationError("Not a valid date.")
+    return None
+
+
+def _validate_timezone_requirement(require_tz: bool, require_naive: bool) -> None:
+    if require_tz and require_naive:
+        raise ValueError("require_tz and require_naive cannot be set together")
+
+
+def _check_timezone_requirement(value: datetime.datetime, require_tz: bool, require_naive: bool) -> None:
+    if require_tz and value.tzinfo is None:
+        raise m.ValidationError("Timezone-aware datetime required.")
+    if require_naive and value.tzinfo is not None:
+        raise m.ValidationError("Naive datetime required.")
+
+
+def _split_tz_name(value: Any) -> tuple[Any, zoneinfo.ZoneInfo | None]:
+    match = _TZ_NAME_SUFFIX_RE.fullmatch(value) if isinstance(value, str) else None
+    if match is None:
+        return value, None
+    try:
+        return match.group(1), zoneinfo.ZoneInfo(match.group(2))
+    except (zoneinfo.ZoneInfoNotFoundError, ValueError):
+        raise m.ValidationError(f"Unknown timezone '{match.group(2)}'.")
+
+
+def _format_with_tz_name(value: datetime.datetime, formats: Sequence[str] | None) -> str:
+    serialized = value.strftime(formats[0]) if formats else value.isoformat()
+    return f"{serialized}[{cast(zoneinfo.ZoneInfo, value.tzinfo).key}]"
+
+
+def _parse_datetime(value: Any, formats: Sequence[str]) -> datetime.datetime:
+    if isinstance(value, str):
+        for format in formats:
+            try:
+                # %f takes up to microseconds, so nanoseconds and alike are truncated
+                if "%f" in format:
+                    return datetime.datetime.strptime(_EXTRA_FRACTION_DIGITS_RE.sub(r"\1", value), format)
+                return datetime.datetime.strptime(value, format)
+            except ValueError:
+                continue
+    raise m.ValidationError("Not a valid datetime.")
+
+
+def _parse_iso_datetime_with_offset(value: str) -> datetime.datetime | None:
+    match = _ISO_DATETIME_WITH_OFFSET_RE.fullmatch(value)
+    if match is None:
+        return None
+    base, seconds, fraction, offset, sign, hours, minutes = match.groups()
+    try:
+        # fromisoformat takes only 3 or 6 fraction digits, so the fraction is padded or truncated to microseconds
+        result = datetime.datetime.fromisoformat(f"{base}:{seconds or '00'}.{(fraction or '').ljust(6, '0')[:6]}")
+    except ValueError:
+        raise m.ValidationError("Not a valid datetime.")
+    if offset == "Z":
+        return result.replace(tzinfo=datetime.timezone.utc)
+    # out of range offsets are rejected instead of failing in timezone or wrapping minutes into hours
+    if int(hours) > 23 or int(minutes or 0) > 59:
+        raise m.ValidationError("Not a valid datetime.")
+    delta = datetime.timedelta(hours=int(hours), minutes=int(minutes or 0))
+    return result.replace(tzinfo=datetime.timezone(-delta if sign == "-" else delta))
+
+
 DateTimeField: Type[m.fields.DateTime]
 EnumField: Type[m.fields.String]
 
@@ -413,19 +1374,66 @@ if _MARSHMALLOW_VERSION_MAJOR >= 3:
     def default_fields(value: Any) -> dict[str, Any]:
         return dict(dump_default=value, load_default=value)
 
+    def set_dump_default(field: m.fields.Field, value: Any) -> None:
+        field.dump_default = value
+
     class DateTimeFieldV3(m.fields.DateTime):
+        def __init__(
+            self,
+            *args: Any,
+            formats: Sequence[str] | None = None,
+            require_tz: bool = False,
+            require_naive: bool = False,
+            default_tz: datetime.tzinfo | None = None,
+            tz_name: bool = False,
+            **kwargs: Any,
+        ):
+            """
+            :param formats: strftime formats, which are tried in order on load, the first one is used on dump
+            :param require_tz: if set, naive datetimes are rejected on load and dump
+            :param require_naive: if set, timezone-aware datetimes are rejected on load and dump,
+                naive ones are loaded as is instead of being treated as UTC
+            :param default_tz: timezone of naive datetimes on load and dump, UTC if not set
+            :param tz_name: if set, datetimes with ZoneInfo are dumped with IANA name suffix like [Europe/Moscow],
+                values with the suffix are loaded in that zone instead of UTC
+            """
+            _validate_timezone_requirement(require_tz, require_naive)
+            super().__init__(*args, **kwargs)
+            self.formats = tuple(formats) if formats else None
+            self.require_tz = require_tz
+            self.require_naive = require_naive
+            self.default_tz = default_tz or datetime.timezone.utc
+            self.tz_name = tz_name
+
         def _deserialize(self, value: Any, attr: Any, data: Any, **kwargs: Any) -> Any:
-            result = super()._deserialize(value, attr, data, **kwargs)
+            value, zone = _split_tz_name(value) if self.tz_name else (value, None)
+            if self.formats:
+                result = _parse_datetime(value, self.formats)
+            else:
+                parsed = _parse_iso_datetime_with_offset(value) if isinstance(value, str) else None
+                result = parsed if parsed is not None else super()._deserialize(value, attr, data, **kwargs)
+            if zone is not None:
+                result = result.replace(tzinfo=zone) if result.tzinfo is None else result.astimezone(zone)
+            _check_timezone_requirement(result, self.require_tz, self.require_naive)
+            if self.require_naive or zone is not None:
+                return result
             if result.tzinfo is None:
-                return result.replace(tzinfo=datetime.timezone.utc)
+                result = result.replace(tzinfo=self.default_tz)
             return result.astimezone(datetime.timezone.utc)
 
         def _serialize(self, value: Any, attr: Any, obj: Any, **kwargs: Any) -> Any:
             if value is None:
                 return None
 
-            if value.tzinfo is None:
-                value = value.replace(tzinfo=datetime.timezone.utc)
+            # a value which breaks the requirement is dumped as is to be reported by validation on load
+            if value.tzinfo is None and not self.require_tz and not self.require_naive:
+                value = value.replace(tzinfo=self.default_tz)
+
+            if self.tz_name and isinstance(value.tzinfo, zoneinfo.ZoneInfo):
+                return _format_with_tz_name(value, self.formats)
+
+            if self.formats:
+                return value.strftime(self.formats[0])
 
             return super()._serialize(value, attr, obj, **kwargs)
 
@@ -440,11 +1448,13 @@ if _MARSHMALLOW_VERSION_MAJOR >= 3:
             enum_type: Type[enum.Enum],
             error: str | None = None,
             extendable_default: Any = m.missing,
+            aliases: Mapping[str, enum.Enum] | None = None,
             **kwargs: Any,
         ):
             """
             :param enum_type: class inherited from Enum and string, where all values are different strings
             :param error: error string pattern with {input} and {choices}
+            :param aliases: wire values to use instead of enum values, alias -> enum instance
             """
             allow_none = (
                 kwargs.get("allow_none") is True
@@ -463,6 +1473,10 @@ if _MARSHMALLOW_VERSION_MAJOR >= 3:
             if allow_none:
                 self.choices.append(None)
 
+            self.aliases = dict(aliases or {})
+            self._validate_aliases(self.enum_type, self.aliases)
+            self._alias_by_enum = {enum_instance: alias for alias, enum_instance in self.aliases.items()}
+
             self.extendable_default = extendable_default
             self._validate_default(self.enum_type, self.extendable_default, allow_none)
             if "default" in kwargs:
@@ -486,7 +1500,7 @@ if _MARSHMALLOW_VERSION_MAJOR >= 3:
             if value is None:
                 return None
             if isinstance(value, self.enum_type):
-                return cast(enum.Enum, value).value
+                return self._alias_by_enum.get(value, cast(enum.Enum, value).value)
             return super()._serialize(value, attr, obj)
 
         def _deserialize(self, value: Any, attr: Any, data: Any, **kwargs: Any) -> Any:
@@ -495,14 +1509,18 @@ if _MARSHMALLOW_VERSION_MAJOR >= 3:
             if isinstance(value, self.enum_type):
                 return value
             string_value = super()._deserialize(value, attr, data)
+            if string_value in self.aliases:
+                return self.aliases[string_value]
             try:
                 return cast(Callable[[str], enum.Enum], self.enum_type)(string_value)
             except ValueError:
                 if self.extendable_default is m.missing:
-                    raise m.ValidationError(self.default_error.format(input=value, choices=self.choices))
+                    raise m.ValidationError(
+                        self.default_error.format(input=value, choices=[*self.aliases, *self.choices])
+                    )
                 return self.extendable_default
             except Exception:
-                raise m.ValidationError(self.default_error.format(input=value, choices=self.choices))
+                raise m.ValidationError(self.default_error.format(input=value, choices=[*self.aliases, *self.choices]))
 
         @staticmethod
         def _validate_enum(enum_type: Any) -> None:
@@ -524,6 +1542,14 @@ if _MARSHMALLOW_VERSION_MAJOR >= 3:
                 if not isinstance(choice, str):
                     raise ValueError(f"There is enum value, which is not a string: {choice}")
 
+        @staticmethod
+        def _validate_aliases(enum_type: Any, aliases: Mapping[str, Any]) -> None:
+            for alias, enum_instance in aliases.items():
+                if not isinstance(alias, str):
+                    raise ValueError(f"There is enum alias, which is not a string: {alias}")
+                if not isinstance(enum_instance, enum_type):
+                    raise ValueError(f"Alias {alias} should point to an instance of enum_type {enum_type}")
+
         @staticmethod
         def _validate_default(enum_type: Any, default: Any, allow_none: bool) -> None:
             if default is m.missing:
@@ -553,15 +1579,76 @@ else:
     def default_fields(value: Any) -> dict[str, Any]:
         return dict(missing=value, default=value)
 
+    def set_dump_default(field: m.fields.Field, value: Any) -> None:
+        field.default = value
+
     class DateTimeFieldV2(m.fields.DateTime):
+        def __init__(
+            self,
+            *args: Any,
+            formats: Sequence[str] | None = None,
+            require_tz: bool = False,
+            require_naive: bool = False,
+            default_tz: datetime.tzinfo | None = None,
+            tz_name: bool = False,
+            **kwargs: Any,
+        ):
+            """
+            :param formats: strftime formats, which are tried in order on load, the first one is used on dump
+            :param require_tz: if set, naive datetimes are rejected on load and dump
+            :param require_naive: if set, timezone-aware datetimes are rejected on load and dump,
+                naive ones are loaded as is instead of being treated as UTC
+            :param default_tz: timezone of naive datetimes on load and dump, UTC if not set
+            :param tz_name: if set, datetimes with ZoneInfo are dumped with IANA name suffix like [Europe/Moscow],
+                values with the suffix are loaded in that zone instead of UTC
+            """
+            _validate_timezone_requirement(require_tz, require_naive)
+            super().__init__(*args, **kwargs)
+            self.formats = tuple(formats) if formats else None
+            self.require_tz = require_tz
+            self.require_naive = require_naive
+            self.default_tz = default_tz or datetime.timezone.utc
+            self.tz_name = tz_name
+
         def _deserialize(self, value: Any, attr: Any, data: Any, **_: Any) -> Any:
-            result = super()._deserialize(value, attr, data)
+            value, zone = _split_tz_name(value) if self.tz_name else (value, None)
+            if self.formats:
+                result = _parse_datetime(value, self.formats)
+            else:
+                # without dateutil marshmallow 2 drops the offset, so offsets are parsed here
+                parsed = _parse_iso_datetime_with_offset(value) if isinstance(value, str) else None
+                result = parsed if parsed is not None else super()._deserialize(value, attr, data)
+            if zone is not None:
+                result = result.replace(tzinfo=zone) if result.tzinfo is None else result.astimezone(zone)
+            _check_timezone_requirement(result, self.require_tz, self.require_naive)
+            if self.require_naive or zone is not None:
+                return result
             if result.tzinfo is None:
-                return result.replace(tzinfo=datetime.timezone.utc)
+                result = result.replace(tzinfo=self.default_tz)
             if dateutil_tz_utc_cls is not None and isinstance(result.tzinfo, dateutil_tz_utc_cls):
                 return result.replace(tzinfo=datetime.timezone.utc)
             return result.astimezone(datetime.timezone.utc)
 
+        def _serialize(self, value: Any, attr: Any, obj: Any, **_: Any) -> Any:
+            if value is None:
+                return super()._serialize(value, attr, obj)
+
+            _check_timezone_requirement(value, self.require_tz, self.require_naive)
+
+            if value.tzinfo is None and not self.require_naive:
+                value = value.replace(tzinfo=self.default_tz)
+
+            if self.tz_name and isinstance(value.tzinfo, zoneinfo.ZoneInfo):
+                return _format_with_tz_name(value, self.formats)
+
+            if self.formats:
+                return value.strftime(self.formats[0])
+
+            if self.require_naive:
+                return value.isoformat()
+
+            return super()._serialize(value, attr, obj)
+
     DateTimeField = DateTimeFieldV2
 
     class EnumFieldV2(m.fields.String):
@@ -573,11 +1660,13 @@ else:
             enum_type: Type[enum.Enum],
             error: str | None = None,
             extendable_default: Any = m.missing,
+            aliases: Mapping[str, enum.Enum] | None = None,
             **kwargs: Any,
         ):
             """
             :param enum_type: class inherited from Enum and string, where all values are different strings
             :param error: error string pattern with {input} and {choices}
+            :param aliases: wire values to use instead of enum values, alias -> enum instance
             """
             allow_none = (
                 kwargs.get("allow_none") is True
@@ -596,6 +1685,10 @@ else:
             if allow_none:
                 self.choices.append(None)
 
+            self.aliases = dict(aliases or {})
+            self._validate_aliases(self.enum_type, self.aliases)
+            self._alias_by_enum = {enum_instance: alias for alias, enum_instance in self.aliases.items()}
+
             self.extendable_default = extendable_default
             self._validate_default(self.enum_type, self.extendable_default, allow_none)
             if "default" in kwargs:
@@ -619,7 +1712,7 @@ else:
             if value is None:
                 return None
             if isinstance(value, self.enum_type):
-                return cast(enum.Enum, value).value
+                return self._alias_by_enum.get(value, cast(enum.Enum, value).value)
             return super()._serialize(value, attr, obj)
 
         def _deserialize(self, value: Any, attr: Any, data: Any, **kwargs: Any) -> Any:
@@ -628,14 +1721,18 @@ else:
             if isinstance(value, self.enum_type):
                 return value
             string_value = super()._deserialize(value, attr, data)
+            if string_value in self.aliases:
+                return self.aliases[string_value]
             try:
                 return cast(Callable[[str], enum.Enum], self.enum_type)(string_value)
             except ValueError:
                 if self.extendable_default is m.missing:
-                    raise m.ValidationError(self.default_error.format(input=value, choices=self.choices))
+                    raise m.ValidationError(
+                        self.default_error.format(input=value, choices=[*self.aliases, *self.choices])
+                    )
                 return self.extendable_default
             except Exception:
-                raise m.ValidationError(self.default_error.format(input=value, choices=self.choices))
+                raise m.ValidationError(self.default_error.format(input=value, choices=[*self.aliases, *self.choices]))
 
         @staticmethod
         def _validate_enum(enum_type: Any) -> None:
@@ -657,6 +1754,14 @@ else:
                 if not isinstance(choice, str):
                     raise ValueError(f"There is enum value, which is not a string: {choice}")
 
+        @staticmethod
+        def _validate_aliases(enum_type: Any, aliases: Mapping[str, Any]) -> None:
+            for alias, enum_instance in aliases.items():
+                if not isinstance(alias, str):
+                    raise ValueError(f"There is enum alias, which is not a string: {alias}")
+                if not isinstance(enum_instance, enum_type):
+                    raise ValueError(f"Alias {alias} should point to an instance of enum_type {enum_type}")
+
         @staticmethod
         def _validate_default(enum_type: Any, default: Any, allow_none: bool) -> None:
             if default is m.missing:
@@ -0,0 +1,123 @@
+from typing import Any, Type
+
+import marshmallow as m
+
+from .bake import bake_schema
+from .fields import ComplexField, DateTimeField, DictField, EnumField, FractionField
+from .naming_case import NamingCase
+
+_MARSHMALLOW_VERSION_MAJOR = int(m.__version__.split(".")[0])
+
+_DRAFT_07 = "http://json-schema.org/draft-07/schema#"
+
+
+def json_schema(cls: type, *, naming_case: NamingCase | None = None) -> dict[str, Any]:
+    """
+    Builds a Draft-07 JSON Schema from the baked marshmallow schema, so it describes exactly what load expects
+    """
+    return {"$schema": _DRAFT_07, **_get_object_schema(bake_schema(cls, naming_case=naming_case)())}
+
+
+def _get_object_schema(schema: m.Schema) -> dict[str, Any]:
+    properties: dict[str, Any] = {}
+    required: list[str] = []
+    for name, field in schema.fields.items():
+        if field.dump_only:
+            continue
+        key = _get_data_key(field) or name
+        properties[key] = _get_field_schema(field)
+        if field.required:
+            required.append(key)
+    result: dict[str, Any] = {"type": "object", "properties": properties}
+    if required:
+        result["required"] = required
+    return result
+
+
+def _get_field_schema(field: m.fields.Field) -> dict[str, Any]:
+    result = _get_value_schema(field)
+    # an empty schema accepts anything including null
+    if field.allow_none and result:
+        return {"anyOf": [result, {"type": "null"}]}
+    return result
+
+
+def _get_value_schema(field: m.fields.Field) -> dict[str, Any]:
+    if isinstance(field, m.fields.Nested):
+        return _get_object_schema(field.schema)
+    if isinstance(field, m.fields.List):
+        return {"type": "array", "items": _get_field_schema(_get_list_item_field(field))}
+    if isinstance(field, DictField) and field.entries:
+        # an array of [key, value] pairs is accepted on load as well as an object
+        key_schema = {} if field.key_field is None else _get_field_schema(field.key_field)
+        value_schema = {} if field.value_field is None else _get_field_schema(field.value_field)
+        entry_schema = {"type": "array", "items": [key_schema, value_schema], "minItems": 2, "maxItems": 2}
+        return {"anyOf": [_get_mapping_schema(field), {"type": "array", "items": entry_schema}]}
+    if isinstance(field, m.fields.Dict):
+        return _get_mapping_schema(field)
+    if isinstance(field, EnumField):
+        return _get_enum_schema(field)
+    if isinstance(field, ComplexField):
+        return {
+            "type": "object",
+            "properties": {"real": {"type": "number"}, "imag": {"type": "number"}},
+            "required": ["real", "imag"],
+        }
+    if isinstance(field, FractionField):
+        return {"type": "string", "pattern": r"^-?\d+/\d+$"}
+    # marshmallow3 Date is inherited from DateTime, so it goes first
+    if isinstance(field, m.fields.Date):
+        return {"type": "string", "format": "date"}
+    # the zone name suffix is not a part of RFC 3339 date-time
+    if isinstance(field, DateTimeField) and field.tz_name:  # type: ignore
+        return {"type": "string"}
+    if isinstance(field, m.fields.DateTime):
+        return {"type": "string", "format": "date-time"}
+    if isinstance(field, m.fields.UUID):
+        return {"type": "string", "format": "uuid"}
+    if isinstance(field, m.fields.Decimal):
+        return {"type": "string"} if field.as_string else {"type": "number"}
+    if isinstance(field, m.fields.Integer):
+        return {"type": "integer"}
+    if isinstance(field, m.fields.Number):
+        return {"type": "number"}
+    if isinstance(field, m.fields.Boolean):
+        return {"type": "boolean"}
+    if isinstance(field, m.fields.String):
+        return {"type": "string"}
+    return {}
+
+
+def _get_mapping_schema(field: m.fields.Dict) -> dict[str, Any]:
+    if isinstance(field, DictField) and field.value_field is not None:
+        return {"type": "object", "additionalProperties": _get_field_schema(field.value_field)}
+    return {"type": "object"}
+
+
+def _get_enum_schema(field: Any) -> dict[str, Any]:
+    # an unknown value is loaded as the extendable default, so any string is accepted
+    if field.extendable_default is not m.missing:
+        return {"type": "string"}
+    # a value of an aliased enum instance is accepted on load as well as its alias
+    values: list[str] = []
+    for enum_instance in field.enum_type:
+        values.extend(alias for alias, aliased_instance in field.aliases.items() if aliased_instance is enum_instance)
+        values.append(enum_instance.value)
+    return {"type": "string", "enum": list(dict.fromkeys(values))}
+
+
+if _MARSHMALLOW_VERSION_MAJOR >= 3:
+
+    def _get_data_key(field: m.fields.Field) -> str | None:
+        return field.data_key
+
+    def _get_list_item_field(field: m.fields.List) -> m.fields.Field:
+        return field.inner
+
+else:
+
+    def _get_data_key(field: m.fields.Field) -> str | None:
+        return field.dump_to  # type: ignore
+
+    def _get_list_item_field(field: m.fields.List) -> m.fields.Field:
+        return field.container  # type: ignore
@@ -1,35 +1,296 @@
-from typing import Any, Callable, Mapping
+import datetime
+import enum
+from typing import Any, Callable, Iterable, Mapping, Sequence
 
+from .fields import IntRange, TimestampUnit, UuidFormat
 from .missing import MISSING
+from .options import NoneValueHandling
 
 
 def metadata(
     *,
     name: str = MISSING,
+    format_spec: str = MISSING,
+    none_value_handling: NoneValueHandling = MISSING,
+    dump_default: Any = MISSING,
+    load_only: bool = MISSING,
+    dump_only: bool = MISSING,
+    allow_none: bool = MISSING,
+    pre_load: Callable[[Any], Any] | None = None,
     validate: Callable[[Any], Any] | None = None,
+    dump_validate: Callable[[Any], Any] | None = None,
 ) -> Mapping[Any, Any]:
     result: dict[Any, Any] = {}
     if name is not MISSING:
         result.update(name=name)
+    if format_spec is not MISSING:
+        result.update(format_spec=format_spec)
+    if none_value_handling is not MISSING:
+        result.update(none_value_handling=none_value_handling)
+    if dump_default is not MISSING:
+        result.update(dump_default=dump_default)
+    if load_only is not MISSING:
+        result.update(load_only=load_only)
+    if dump_only is not MISSING:
+        result.update(dump_only=dump_only)
+    if allow_none is not MISSING:
+        result.update(allow_none=allow_none)
+    if pre_load is not None:
+        result.update(pre_load=pre_load)
     if validate is not None:
         result.update(validate=validate)
+    if dump_validate is not None:
+        result.update(dump_validate=dump_validate)
+    return result
+
+
+def bool_metadata(
+    *,
+    name: str = MISSING,
+    truthy: Iterable[Any] = MISSING,
+    falsy: Iterable[Any] = MISSING,
+    bool_strings: bool = MISSING,
+    validate: Callable[[Any], Any] | None = None,
+    dump_validate: Callable[[Any], Any] | None = None,
+) -> Mapping[Any, Any]:
+    result: dict[Any, Any] = {}
+    if name is not MISSING:
+        result.update(name=name)
+    if truthy is not MISSING:
+        result.update(truthy=truthy)
+    if falsy is not MISSING:
+        result.update(falsy=falsy)
+    if bool_strings is not MISSING:
+        result.update(bool_strings=bool_strings)
+    if validate is not None:
+        result.update(validate=validate)
+    if dump_validate is not None:
+        result.update(dump_validate=dump_validate)
+    return result
+
+
+def int_metadata(
+    *,
+    name: str = MISSING,
+    thousands_separator: str = MISSING,
+    int_range: IntRange = MISSING,
+    validate: Callable[[Any], Any] | None = None,
+    dump_validate: Callable[[Any], Any] | None = None,
+) -> Mapping[Any, Any]:
+    result: dict[Any, Any] = {}
+    if name is not MISSING:
+        result.update(name=name)
+    if thousands_separator is not MISSING:
+        result.update(thousands_separator=thousands_separator)
+    if int_range is not MISSING:
+        result.update(int_range=int_range)
+    if validate is not None:
+        result.update(validate=validate)
+    if dump_validate is not None:
+        result.update(dump_validate=dump_validate)
     return result
 
 
 def decimal_metadata(
     *,
     name: str = MISSING,
-    places: int = MISSING,
+    places: int | None = MISSING,
+    dump_places: int | None = MISSING,
     as_string: bool = MISSING,
+    thousands_separator: str = MISSING,
+    allow_nan: bool = MISSING,
+    normalize: bool = MISSING,
+    scientific: bool = MISSING,
+    tag: str = MISSING,
+    none_as_zero: bool = MISSING,
     validate: Callable[[Any], Any] | None = None,
+    dump_validate: Callable[[Any], Any] | None = None,
 ) -> Mapping[Any, Any]:
     result: dict[Any, Any] = {}
     if name is not MISSING:
         result.update(name=name)
     if places is not MISSING:
         result.update(places=places)
+    if dump_places is not MISSING:
+        result.update(dump_places=dump_places)
     if as_string is not MISSING:
         result.update(as_string=as_string)
+    if thousands_separator is not MISSING:
+        result.update(thousands_separator=thousands_separator)
+    if allow_nan is not MISSING:
+        result.update(allow_nan=allow_nan)
+    if normalize is not MISSING:
+        result.update(normalize=normalize)
+    if scientific is not MISSING:
+        result.update(scientific=scientific)
+    if tag is not MISSING:
+        result.update(tag=tag)
+    if none_as_zero is not MISSING:
+        result.update(none_as_zero=none_as_zero)
+    if validate is not None:
+        result.update(validate=validate)
+    if dump_validate is not None:
+        result.update(dump_validate=dump_validate)
+    return result
+
+
+def float_metadata(
+    *,
+    name: str = MISSING,
+    places: int = MISSING,
+    allow_nan: bool = MISSING,
+    thousands_separator: str = MISSING,
+    allow_precision_loss: bool = MISSING,
+    none_as_zero: bool = MISSING,
+    validate: Callable[[Any], Any] | None = None,
+    dump_validate: Callable[[Any], Any] | None = None,
+) -> Mapping[Any, Any]:
+    result: dict[Any, Any] = {}
+    if name is not MISSING:
+        result.update(name=name)
+    if places is not MISSING:
+        result.update(places=places)
+    if allow_nan is not MISSING:
+        result.update(allow_nan=allow_nan)
+    if thousands_separator is not MISSING:
+        result.update(thousands_separator=thousands_separator)
+    if allow_precision_loss is not MISSING:
+        result.update(allow_precision_loss=allow_precision_loss)
+    if none_as_zero is not MISSING:
+        result.update(none_as_zero=none_as_zero)
+    if validate is not None:
+        result.update(validate=validate)
+    if dump_validate is not None:
+        result.update(dump_validate=dump_validate)
+    return result
+
+
+def custom_metadata(
+    *,
+    serializer: Callable[[Any], Any],
+    deserializer: Callable[[Any], Any],
+    name: str = MISSING,
+    validate: Callable[[Any], Any] | None = None,
+    dump_validate: Callable[[Any], Any] | None = None,
+) -> Mapping[Any, Any]:
+    result: dict[Any, Any] = dict(serializer=serializer, deserializer=deserializer)
+    if name is not MISSING:
+        result.update(name=name)
+    if validate is not None:
+        result.update(validate=validate)
+    if dump_validate is not None:
+        result.update(dump_validate=dump_validate)
+    return result
+
+
+def enum_metadata(
+    *,
+    name: str = MISSING,
+    aliases: Mapping[str, enum.Enum] = MISSING,
+) -> Mapping[Any, Any]:
+    result: dict[Any, Any] = {}
+    if name is not MISSING:
+        result.update(name=name)
+    if aliases is not MISSING:
+        result.update(aliases=aliases)
+    return result
+
+
+def uuid_metadata(
+    *,
+    name: str = MISSING,
+    uuid_format: UuidFormat = MISSING,
+    uppercase: bool = MISSING,
+    version: int = MISSING,
+    validate: Callable[[Any], Any] | None = None,
+    dump_validate: Callable[[Any], Any] | None = None,
+) -> Mapping[Any, Any]:
+    result: dict[Any, Any] = {}
+    if name is not MISSING:
+        result.update(name=name)
+    if uuid_format is not MISSING:
+        result.update(uuid_format=uuid_format)
+    if uppercase is not MISSING:
+        result.update(uppercase=uppercase)
+    if version is not MISSING:
+        result.update(version=version)
+    if validate is not None:
+        result.update(validate=validate)
+    if dump_validate is not None:
+        result.update(dump_validate=dump_validate)
+    return result
+
+
+def datetime_metadata(
+    *,
+    name: str = MISSING,
+    formats: Sequence[str] = MISSING,
+    require_tz: bool = MISSING,
+    require_naive: bool = MISSING,
+    default_tz: datetime.tzinfo = MISSING,
+    tz_name: bool = MISSING,
+    validate: Callable[[Any], Any] | None = None,
+    dump_validate: Callable[[Any], Any] | None = None,
+) -> Mapping[Any, Any]:
+    result: dict[Any, Any] = {}
+    if name is not MISSING:
+        result.update(name=name)
+    if formats is not MISSING:
+        result.update(formats=formats)
+    if require_tz is not MISSING:
+        result.update(require_tz=require_tz)
+    if require_naive is not MISSING:
+        result.update(require_naive=require_naive)
+    if default_tz is not MISSING:
+        result.update(default_tz=default_tz)
+    if tz_name is not MISSING:
+        result.update(tz_name=tz_name)
+    if validate is not None:
+        result.update(validate=validate)
+    if dump_validate is not None:
+        result.update(dump_validate=dump_validate)
+    return result
+
+
+def date_metadata(
+    *,
+    name: str = MISSING,
+    timestamp_unit: TimestampUnit | None = MISSING,
+    iso_week_and_ordinal: bool = MISSING,
+    accept_datetime: bool = MISSING,
+    validate: Callable[[Any], Any] | None = None,
+    dump_validate: Callable[[Any], Any] | None = None,
+) -> Mapping[Any, Any]:
+    result: dict[Any, Any] = {}
+    if name is not MISSING:
+        result.update(name=name)
+    if timestamp_unit is not MISSING:
+        result.update(timestamp_unit=timestamp_unit)
+    if iso_week_and_ordinal is not MISSING:
+        result.update(iso_week_and_ordinal=iso_week_and_ordinal)
+    if accept_datetime is not MISSING:
+        result.update(accept_datetime=accept_datetime)
+    if validate is not None:
+        result.update(validate=validate)
+    if dump_validate is not None:
+        result.update(dump_validate=dump_validate)
+    return result
+
+
+def dict_metadata(
+    *,
+    name: str = MISSING,
+    entries: bool = MISSING,
+    validate: Callable[[Any], Any] | None = None,
+    dump_validate: Callable[[Any], Any] | None = None,
+) -> Mapping[Any, Any]:
+    result: dict[Any, Any] = {}
+    if name is not MISSING:
+        result.update(name=name)
+    if entries is not MISSING:
+        result.update(entries=entries)
     if validate is not None:
         result.update(validate=validate)
+    if dump_validate is not None:
+        result.update(dump_validate=dump_validate)
     return result
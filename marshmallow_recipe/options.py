@@ -1,6 +1,6 @@
 import dataclasses
 import enum
-from typing import Any
+from typing import Any, Callable, Mapping
 
 from .naming_case import DEFAULT_CASE, NamingCase
 
@@ -19,11 +19,33 @@ class NoneValueHandling(str, enum.Enum):
 class DataclassOptions:
     none_value_handling: NoneValueHandling
     naming_case: NamingCase
+    strip_all_strings: bool
+    intern_strings: bool
+    strict_types: bool
+    all_optional: bool
+    pre_load: Callable[[Any], Any] | None
+    post_load: Callable[[Any], Any] | None
+    validate: Callable[[Any], Any] | None
+    computed_fields: Mapping[str, Any]
+    positional: bool
+    max_items: int | None
+    max_keys: int | None
 
 
 _DEFAULT_OPTIONS = DataclassOptions(
     none_value_handling=NoneValueHandling.IGNORE,
     naming_case=DEFAULT_CASE,
+    strip_all_strings=False,
+    intern_strings=False,
+    strict_types=False,
+    all_optional=False,
+    pre_load=None,
+    post_load=None,
+    validate=None,
+    computed_fields={},
+    positional=False,
+    max_items=None,
+    max_keys=None,
 )
 
 
@@ -31,7 +53,34 @@ def options(
     *,
     none_value_handling: NoneValueHandling = _DEFAULT_OPTIONS.none_value_handling,
     naming_case: NamingCase = _DEFAULT_OPTIONS.naming_case,
+    strip_all_strings: bool = _DEFAULT_OPTIONS.strip_all_strings,
+    intern_strings: bool = _DEFAULT_OPTIONS.intern_strings,
+    strict_types: bool = _DEFAULT_OPTIONS.strict_types,
+    all_optional: bool = _DEFAULT_OPTIONS.all_optional,
+    pre_load: Callable[[Any], Any] | None = _DEFAULT_OPTIONS.pre_load,
+    post_load: Callable[[Any], Any] | None = _DEFAULT_OPTIONS.post_load,
+    validate: Callable[[Any], Any] | None = _DEFAULT_OPTIONS.validate,
+    computed_fields: Mapping[str, Any] = _DEFAULT_OPTIONS.computed_fields,
+    positional: bool = _DEFAULT_OPTIONS.positional,
+    max_items: int | None = _DEFAULT_OPTIONS.max_items,
+    max_keys: int | None = _DEFAULT_OPTIONS.max_keys,
 ):
+    """
+    :param intern_strings: if set, string values of raw data are interned on load,
+        so repeated values like codes and statuses share a single object
+    :param pre_load: a callable which receives raw data of the whole object before its fields are loaded
+    :param post_load: a callable which receives a loaded instance and returns the final object
+    :param validate: a callable which receives a dict of loaded field values, where failed fields are absent,
+        its errors are reported under _schema together with errors of fields
+    :param computed_fields: attribute name -> type of properties, which are dumped after fields and ignored on load
+    :param positional: if set, an object is dumped as an array of field values in the order of fields,
+        it is loaded from such an array as well as from an object
+    :param max_items: if set, lists of fields with more items are rejected on load with "Too many items (limit N).",
+        the check goes before items are loaded
+    :param max_keys: if set, dicts of fields with more keys are rejected on load with "Too many keys (limit N).",
+        the check goes before keys and values are loaded
+    """
+
     def wrap(cls: Any):
         setattr(
             cls,
@@ -39,6 +88,17 @@ def options(
             DataclassOptions(
                 none_value_handling=none_value_handling,
                 naming_case=naming_case,
+                strip_all_strings=strip_all_strings,
+                intern_strings=intern_strings,
+                strict_types=strict_types,
+                all_optional=all_optional,
+                pre_load=pre_load,
+                post_load=post_load,
+                validate=validate,
+                computed_fields=computed_fields,
+                positional=positional,
+                max_items=max_items,
+                max_keys=max_keys,
             ),
         )
         return cls
@@ -1,9 +1,11 @@
 import dataclasses
+import threading
 from typing import Any, Type, TypeVar, cast
 
 import marshmallow as m
 
-from .bake import bake_schema
+from .bake import bake_schema, get_nested_classes, validation
+from .fields import dump_validation
 from .naming_case import NamingCase
 
 _T = TypeVar("_T")
@@ -18,17 +20,57 @@ class _SchemaKey:
 
 
 _schemas: dict[_SchemaKey, m.Schema] = {}
+# guards lookups and mutations of the cache, so an eviction cannot be undone by a schema baked concurrently
+_schemas_lock = threading.Lock()
+
+
+# Evicted schemas are only dropped from the cache: a load or a dump which is already in progress
+# keeps using the schema instance it has obtained.
+def unregister(cls: type) -> None:
+    with _schemas_lock:
+        for key in list(_schemas):
+            if key.cls is cls:
+                _schemas.pop(key, None)
+
+
+def clear_cache() -> None:
+    with _schemas_lock:
+        _schemas.clear()
+
+
+def registered_classes() -> set[type]:
+    with _schemas_lock:
+        return {key.cls for key in _schemas}
+
+
+def warm(cls: type, *, naming_case: NamingCase | None = None) -> None:
+    """
+    Registers schemas of cls and of all classes reachable from its fields, so the first load or dump of any of them
+    does not bake a schema
+    """
+    pending = [cls]
+    warmed: set[type] = set()
+    while pending:
+        current = pending.pop(0)
+        if current in warmed:
+            continue
+        warmed.add(current)
+        for many in (False, True):
+            schema(current, many=many, naming_case=naming_case)
+        pending.extend(get_nested_classes(current))
+
 
 if _MARSHMALLOW_VERSION_MAJOR >= 3:
 
     def schema(cls: Type[_T], *, many: bool = False, naming_case: NamingCase | None = None) -> m.Schema:
         key = _SchemaKey(cls=cls, many=many, naming_case=naming_case)
-        existent_schema = _schemas.get(key)
-        if existent_schema is not None:
-            return existent_schema
-        new_schema = bake_schema(cls, naming_case=naming_case)(many=many)
-        _schemas[key] = new_schema
-        return new_schema
+        with _schemas_lock:
+            existent_schema = _schemas.get(key)
+            if existent_schema is not None:
+                return existent_schema
+            new_schema = bake_schema(cls, naming_case=naming_case)(many=many)
+            _schemas[key] = new_schema
+            return new_schema
 
     def load(cls: Type[_T], data: dict[str, Any], *, naming_case: NamingCase | None = None) -> _T:
         loaded: _T = schema(cls, naming_case=naming_case).load(data)
@@ -38,36 +80,62 @@ if _MARSHMALLOW_VERSION_MAJOR >= 3:
         loaded: list[_T] = schema(cls, many=True, naming_case=naming_case).load(data)
         return loaded
 
+    def validate(cls: type, data: dict[str, Any], *, naming_case: NamingCase | None = None) -> dict[str, Any] | None:
+        with validation():
+            errors: dict[str, Any] = schema(cls, naming_case=naming_case).validate(data)
+        return errors or None
+
     def dump(
         data: _T,
         *,
         naming_case: NamingCase | None = None,
-    ) -> dict[str, Any]:
+    ) -> Any:
         data_schema = schema(type(data), naming_case=naming_case)
-        dumped: dict[str, Any] = data_schema.dump(data)
-        if errors := data_schema.validate(dumped):
+        dumped = data_schema.dump(data)
+        with dump_validation():
+            errors = data_schema.validate(dumped, partial=_get_unvalidated_names(data_schema))
+        if errors:
             raise m.ValidationError(errors)
         return dumped
 
-    def dump_many(data: list[_T], *, naming_case: NamingCase | None = None) -> list[dict[str, Any]]:
+    def dump_many(data: list[_T], *, naming_case: NamingCase | None = None) -> list[Any]:
         if not data:
             return []
         data_schema = schema(type(data[0]), many=True, naming_case=naming_case)
-        dumped: list[dict[str, Any]] = data_schema.dump(data)
-        if errors := data_schema.validate(dumped):
+        dumped: list[Any] = data_schema.dump(data)
+        with dump_validation():
+            errors = data_schema.validate(dumped, partial=_get_unvalidated_names(data_schema))
+        if errors:
             raise m.ValidationError(errors)
         return dumped
 
+    # load only fields are absent in dumped data and formatted ones are dropped before validating it,
+    # so they are not required
+    def _get_unvalidated_names(data_schema: m.Schema) -> tuple[str, ...]:
+        names: list[str] = []
+        formatted_names = getattr(data_schema, "formatted_names", frozenset())
+        for name, field in data_schema.fields.items():
+            if field.load_only or name in formatted_names:
+                names.append(name)
+            # marshmallow matches a nested name by an attribute name, but its prefix by a data key
+            prefix = field.data_key or name
+            if isinstance(field, m.fields.List):
+                field = field.inner
+            if isinstance(field, m.fields.Nested):
+                names.extend(f"{prefix}.{nested_name}" for nested_name in _get_unvalidated_names(field.schema))
+        return tuple(names)
+
 else:
 
     def schema(cls: Type[_T], *, many: bool = False, naming_case: NamingCase | None = None) -> m.Schema:
         key = _SchemaKey(cls=cls, many=many, naming_case=naming_case)
-        existent_schema = _schemas.get(key)
-        if existent_schema is not None:
-            return existent_schema
-        new_schema = bake_schema(cls, naming_case=naming_case)(strict=True, many=many)  # type: ignore
-        _schemas[key] = new_schema
-        return new_schema
+        with _schemas_lock:
+            existent_schema = _schemas.get(key)
+            if existent_schema is not None:
+                return existent_schema
+            new_schema = bake_schema(cls, naming_case=naming_case)(strict=True, many=many)  # type: ignore
+            _schemas[key] = new_schema
+            return new_schema
 
     def load(cls: Type[_T], data: dict[str, Any], *, naming_case: NamingCase | None = None) -> _T:
         loaded, _ = schema(cls, naming_case=naming_case).load(data)
@@ -77,19 +145,28 @@ else:
         loaded, _ = schema(cls, many=True, naming_case=naming_case).load(data)
         return cast(list[_T], loaded)
 
+    def validate(cls: type, data: dict[str, Any], *, naming_case: NamingCase | None = None) -> dict[str, Any] | None:
+        # a strict schema raises errors instead of returning them
+        try:
+            with validation():
+                schema(cls, naming_case=naming_case).validate(data)
+        except m.ValidationError as e:
+            return cast(dict[str, Any], e.messages)
+        return None
+
     def dump(
         data: _T,
         *,
         naming_case: NamingCase | None = None,
-    ) -> dict[str, Any]:
+    ) -> Any:
         dumped, _ = schema(type(data), naming_case=naming_case).dump(data)
-        return cast(dict[str, Any], dumped)
+        return dumped
 
-    def dump_many(data: list[_T], *, naming_case: NamingCase | None = None) -> list[dict[str, Any]]:
+    def dump_many(data: list[_T], *, naming_case: NamingCase | None = None) -> list[Any]:
         if not data:
             return []
         dumped, _ = schema(type(data[0]), many=True, naming_case=naming_case).dump(data)
-        return cast(list[dict[str, Any]], dumped)
+        return cast(list[Any], dumped)
 
 
 EmptySchema = m.Schema
@@ -2,10 +2,12 @@ import dataclasses
 import datetime
 import decimal
 import enum
+import fractions
 import inspect
+import pathlib
 import unittest.mock
 import uuid
-from typing import Any, Dict, Optional
+from typing import Any, Dict, Optional, TypedDict
 
 import marshmallow as m
 import pytest
@@ -55,6 +57,10 @@ class EmptyDataclass:
     pass
 
 
+class EmptyTypedDict(TypedDict):
+    pass
+
+
 class Enum(str, enum.Enum):
     pass
 
@@ -70,19 +76,20 @@ EMPTY_SCHEMA = m.Schema()
         (Any, {}, m.fields.Raw(allow_none=True, **default_fields(None))),
         (Any, mr.metadata(name="i"), m.fields.Raw(allow_none=True, **default_fields(None), **data_key_fields("i"))),
         # simple types: bool
-        (bool, {}, m.fields.Bool(required=True)),
-        (Optional[bool], {}, m.fields.Bool(allow_none=True, **default_fields(None))),
-        (bool | None, {}, m.fields.Bool(allow_none=True, **default_fields(None))),
-        (bool, mr.metadata(name="i"), m.fields.Bool(required=True, **data_key_fields("i"))),
+        (bool, {}, mr.fields.BoolField(required=True)),
+        (Optional[bool], {}, mr.fields.BoolField(allow_none=True, **default_fields(None))),
+        (bool | None, {}, mr.fields.BoolField(allow_none=True, **default_fields(None))),
+        (bool, mr.metadata(name="i"), mr.fields.BoolField(required=True, **data_key_fields("i"))),
+        (bool, mr.bool_metadata(bool_strings=True), mr.fields.BoolField(required=True, bool_strings=True)),
         (
             Optional[bool],
             mr.metadata(name="i"),
-            m.fields.Bool(allow_none=True, **default_fields(None), **data_key_fields("i")),
+            mr.fields.BoolField(allow_none=True, **default_fields(None), **data_key_fields("i")),
         ),
         (
             bool | None,
             mr.metadata(name="i"),
-            m.fields.Bool(allow_none=True, **default_fields(None), **data_key_fields("i")),
+            mr.fields.BoolField(allow_none=True, **default_fields(None), **data_key_fields("i")),
         ),
         # simple types: str
         (str, {}, m.fields.Str(required=True)),
@@ -100,78 +107,115 @@ EMPTY_SCHEMA = m.Schema()
             m.fields.Str(allow_none=True, **default_fields(None), **data_key_fields("i")),
         ),
         # simple types: int
-        (int, {}, m.fields.Int(required=True)),
-        (Optional[int], {}, m.fields.Int(allow_none=True, **default_fields(None))),
-        (int | None, {}, m.fields.Int(allow_none=True, **default_fields(None))),
-        (int, mr.metadata(name="i"), m.fields.Int(required=True, **data_key_fields("i"))),
+        (int, {}, mr.fields.IntField(required=True)),
+        (Optional[int], {}, mr.fields.IntField(allow_none=True, **default_fields(None))),
+        (int | None, {}, mr.fields.IntField(allow_none=True, **default_fields(None))),
+        (int, mr.metadata(name="i"), mr.fields.IntField(required=True, **data_key_fields("i"))),
+        (int, mr.int_metadata(thousands_separator=","), mr.fields.IntField(required=True, thousands_separator=",")),
+        (
+            int,
+            mr.int_metadata(int_range=mr.IntRange.INT32),
+            mr.fields.IntField(required=True, int_range=mr.IntRange.INT32),
+        ),
         (
             Optional[int],
             mr.metadata(name="i"),
-            m.fields.Int(allow_none=True, **default_fields(None), **data_key_fields("i")),
+            mr.fields.IntField(allow_none=True, **default_fields(None), **data_key_fields("i")),
         ),
         (
             int | None,
             mr.metadata(name="i"),
-            m.fields.Int(allow_none=True, **default_fields(None), **data_key_fields("i")),
+            mr.fields.IntField(allow_none=True, **default_fields(None), **data_key_fields("i")),
         ),
         # simple types: float
-        (float, {}, m.fields.Float(required=True)),
-        (Optional[float], {}, m.fields.Float(allow_none=True, **default_fields(None))),
-        (float | None, {}, m.fields.Float(allow_none=True, **default_fields(None))),
-        (float, mr.metadata(name="i"), m.fields.Float(required=True, **data_key_fields("i"))),
+        (float, {}, mr.fields.FloatField(required=True)),
+        (Optional[float], {}, mr.fields.FloatField(allow_none=True, **default_fields(None))),
+        (float | None, {}, mr.fields.FloatField(allow_none=True, **default_fields(None))),
+        (float, mr.metadata(name="i"), mr.fields.FloatField(required=True, **data_key_fields("i"))),
         (
             Optional[float],
             mr.metadata(name="i"),
-            m.fields.Float(allow_none=True, **default_fields(None), **data_key_fields("i")),
+            mr.fields.FloatField(allow_none=True, **default_fields(None), **data_key_fields("i")),
         ),
         (
             float | None,
             mr.metadata(name="i"),
-            m.fields.Float(allow_none=True, **default_fields(None), **data_key_fields("i")),
+            mr.fields.FloatField(allow_none=True, **default_fields(None), **data_key_fields("i")),
+        ),
+        (
+            float,
+            mr.float_metadata(name="i", places=2),
+            mr.fields.FloatField(required=True, places=2, **data_key_fields("i")),
+        ),
+        (
+            float | None,
+            mr.float_metadata(name="i", places=2),
+            mr.fields.FloatField(allow_none=True, places=2, **default_fields(None), **data_key_fields("i")),
+        ),
+        (
+            float,
+            mr.float_metadata(allow_nan=True),
+            mr.fields.FloatField(required=True, allow_nan=True),
         ),
+        # simple types: complex
+        (complex, {}, mr.fields.ComplexField(required=True)),
+        (complex | None, {}, mr.fields.ComplexField(allow_none=True, **default_fields(None))),
+        (
+            complex,
+            mr.metadata(name="i"),
+            mr.fields.ComplexField(required=True, **data_key_fields("i")),
+        ),
+        # simple types: fraction
+        (fractions.Fraction, {}, mr.fields.FractionField(required=True)),
+        (fractions.Fraction | None, {}, mr.fields.FractionField(allow_none=True, **default_fields(None))),
         # simple types: uuid
-        (uuid.UUID, {}, m.fields.UUID(required=True)),
-        (Optional[uuid.UUID], {}, m.fields.UUID(allow_none=True, **default_fields(None))),
-        (uuid.UUID | None, {}, m.fields.UUID(allow_none=True, **default_fields(None))),
-        (uuid.UUID, mr.metadata(name="i"), m.fields.UUID(required=True, **data_key_fields("i"))),
+        (uuid.UUID, {}, mr.fields.UuidField(required=True)),
+        (Optional[uuid.UUID], {}, mr.fields.UuidField(allow_none=True, **default_fields(None))),
+        (uuid.UUID | None, {}, mr.fields.UuidField(allow_none=True, **default_fields(None))),
+        (uuid.UUID, mr.metadata(name="i"), mr.fields.UuidField(required=True, **data_key_fields("i"))),
+        (
+            uuid.UUID,
+            mr.uuid_metadata(uuid_format=mr.UuidFormat.URN, uppercase=True),
+            mr.fields.UuidField(required=True, uuid_format=mr.UuidFormat.URN, uppercase=True),
+        ),
         (
             Optional[uuid.UUID],
             mr.metadata(name="i"),
-            m.fields.UUID(allow_none=True, **default_fields(None), **data_key_fields("i")),
+            mr.fields.UuidField(allow_none=True, **default_fields(None), **data_key_fields("i")),
         ),
         (
             uuid.UUID | None,
             mr.metadata(name="i"),
-            m.fields.UUID(allow_none=True, **default_fields(None), **data_key_fields("i")),
+            mr.fields.UuidField(allow_none=True, **default_fields(None), **data_key_fields("i")),
         ),
         # simple types: decimal
-        (decimal.Decimal, {}, m.fields.Decimal(required=True, places=2, as_string=True)),
+        (decimal.Decimal, {}, mr.fields.DecimalField(required=True, places=2, as_string=True)),
         (
             Optional[decimal.Decimal],
             {},
-            m.fields.Decimal(allow_none=True, **default_fields(None), places=2, as_string=True),
+            mr.fields.DecimalField(allow_none=True, **default_fields(None), places=2, as_string=True),
         ),
         (
             decimal.Decimal | None,
             {},
-            m.fields.Decimal(allow_none=True, **default_fields(None), places=2, as_string=True),
+            mr.fields.DecimalField(allow_none=True, **default_fields(None), places=2, as_string=True),
         ),
         (
             decimal.Decimal,
             mr.decimal_metadata(name="i", places=4, as_string=False),
-            m.fields.Decimal(required=True, **data_key_fields("i"), places=4, as_string=False),
+            mr.fields.DecimalField(required=True, **data_key_fields("i"), places=4, as_string=False),
         ),
         (
             Optional[decimal.Decimal],
             mr.decimal_metadata(name="i", places=4, as_string=False),
-            m.fields.Decimal(
+            mr.fields.DecimalField(
                 allow_none=True, **default_fields(None), places=4, as_string=False, **data_key_fields("i")
             ),
         ),
         (
             decimal.Decimal | None,
             mr.decimal_metadata(name="i", places=4, as_string=False),
-            m.fields.Decimal(
+            mr.fields.DecimalField(
                 allow_none=True, **default_fields(None), places=4, as_string=False, **data_key_fields("i")
             ),
         ),
@@ -203,31 +247,31 @@ EMPTY_SCHEMA = m.Schema()
             mr.fields.DateTimeField(allow_none=True, **default_fields(None), **data_key_fields("i")),
         ),
         # simple types: date
-        (datetime.date, {}, m.fields.Date(required=True)),
+        (datetime.date, {}, mr.fields.DateField(required=True)),
         (
             Optional[datetime.date],
             {},
-            m.fields.Date(allow_none=True, **default_fields(None)),
+            mr.fields.DateField(allow_none=True, **default_fields(None)),
         ),
         (
             datetime.date | None,
             {},
-            m.fields.Date(allow_none=True, **default_fields(None)),
+            mr.fields.DateField(allow_none=True, **default_fields(None)),
         ),
         (
             datetime.date,
             mr.metadata(name="i"),
-            m.fields.Date(required=True, **data_key_fields("i")),
+            mr.fields.DateField(required=True, **data_key_fields("i")),
         ),
         (
             Optional[datetime.date],
             mr.metadata(name="i"),
-            m.fields.Date(allow_none=True, **default_fields(None), **data_key_fields("i")),
+            mr.fields.DateField(allow_none=True, **default_fields(None), **data_key_fields("i")),
         ),
         (
             datetime.date | None,
             mr.metadata(name="i"),
-            m.fields.Date(allow_none=True, **default_fields(None), **data_key_fields("i")),
+            mr.fields.DateField(allow_none=True, **default_fields(None), **data_key_fields("i")),
         ),
         # enum
         (Enum, {}, mr.fields.EnumField(enum_type=Enum, required=True)),
@@ -244,10 +288,24 @@ EMPTY_SCHEMA = m.Schema()
             mr.metadata(name="i"),
             mr.fields.EnumField(enum_type=Enum, allow_none=True, **default_fields(None), **data_key_fields("i")),
         ),
+        # path
+        (pathlib.Path, {}, mr.fields.PathField(path_type=pathlib.Path, required=True)),
+        (
+            pathlib.PurePosixPath | None,
+            {},
+            mr.fields.PathField(path_type=pathlib.PurePosixPath, allow_none=True, **default_fields(None)),
+        ),
+        (
+            pathlib.Path,
+            mr.metadata(name="i"),
+            mr.fields.PathField(path_type=pathlib.Path, required=True, **data_key_fields("i")),
+        ),
         # dataclass
         (EmptyDataclass, {}, m.fields.Nested(EMPTY_SCHEMA, required=True)),
         (Optional[EmptyDataclass], {}, m.fields.Nested(EMPTY_SCHEMA, allow_none=True, **default_fields(None))),
         (EmptyDataclass | None, {}, m.fields.Nested(EMPTY_SCHEMA, allow_none=True, **default_fields(None))),
+        (EmptyTypedDict, {}, m.fields.Nested(EMPTY_SCHEMA, required=True)),
+        (EmptyTypedDict | None, {}, m.fields.Nested(EMPTY_SCHEMA, allow_none=True, **default_fields(None))),
         (
             EmptyDataclass,
             mr.metadata(name="i"),
@@ -264,57 +322,57 @@ EMPTY_SCHEMA = m.Schema()
             m.fields.Nested(EMPTY_SCHEMA, allow_none=True, **default_fields(None), **data_key_fields("i")),
         ),
         # containers: list[T]
-        (list[bool], {}, m.fields.List(m.fields.Bool(required=True), required=True)),
+        (list[bool], {}, mr.fields.ListField(mr.fields.BoolField(required=True), required=True)),
         (
             list[Optional[bool]],
             {},
-            m.fields.List(m.fields.Bool(allow_none=True, **default_fields(None)), required=True),
+            mr.fields.ListField(mr.fields.BoolField(allow_none=True, **default_fields(None)), required=True),
         ),
         (
             list[bool | None],
             {},
-            m.fields.List(m.fields.Bool(allow_none=True, **default_fields(None)), required=True),
+            mr.fields.ListField(mr.fields.BoolField(allow_none=True, **default_fields(None)), required=True),
         ),
         (
             Optional[list[bool]],
             {},
-            m.fields.List(m.fields.Bool(required=True), allow_none=True, **default_fields(None)),
+            mr.fields.ListField(mr.fields.BoolField(required=True), allow_none=True, **default_fields(None)),
         ),
         (
             Optional[list[Optional[bool]]],
             {},
-            m.fields.List(
-                m.fields.Bool(allow_none=True, **default_fields(None)), allow_none=True, **default_fields(None)
+            mr.fields.ListField(
+                mr.fields.BoolField(allow_none=True, **default_fields(None)), allow_none=True, **default_fields(None)
             ),
         ),
         (
             list[bool | None] | None,
             {},
-            m.fields.List(
-                m.fields.Bool(allow_none=True, **default_fields(None)), allow_none=True, **default_fields(None)
+            mr.fields.ListField(
+                mr.fields.BoolField(allow_none=True, **default_fields(None)), allow_none=True, **default_fields(None)
             ),
         ),
         # containers: list[T] where T: dataclass
-        (list[EmptyDataclass], {}, m.fields.List(m.fields.Nested(EMPTY_SCHEMA, required=True), required=True)),
+        (list[EmptyDataclass], {}, mr.fields.ListField(m.fields.Nested(EMPTY_SCHEMA, required=True), required=True)),
         (
             list[Optional[EmptyDataclass]],
             {},
-            m.fields.List(m.fields.Nested(EMPTY_SCHEMA, allow_none=True, **default_fields(None)), required=True),
+            mr.fields.ListField(m.fields.Nested(EMPTY_SCHEMA, allow_none=True, **default_fields(None)), required=True),
         ),
         (
             list[EmptyDataclass | None],
             {},
-            m.fields.List(m.fields.Nested(EMPTY_SCHEMA, allow_none=True, **default_fields(None)), required=True),
+            mr.fields.ListField(m.fields.Nested(EMPTY_SCHEMA, allow_none=True, **default_fields(None)), required=True),
         ),
         (
             Optional[list[EmptyDataclass]],
             {},
-            m.fields.List(m.fields.Nested(EMPTY_SCHEMA, required=True), allow_none=True, **default_fields(None)),
+            mr.fields.ListField(m.fields.Nested(EMPTY_SCHEMA, required=True), allow_none=True, **default_fields(None)),
         ),
         (
             Optional[list[Optional[EmptyDataclass]]],
             {},
-            m.fields.List(
+            mr.fields.ListField(
                 m.fields.Nested(EMPTY_SCHEMA, allow_none=True, **default_fields(None)),
                 allow_none=True,
                 **default_fields(None),
@@ -323,48 +381,48 @@ EMPTY_SCHEMA = m.Schema()
         (
             list[EmptyDataclass | None] | None,
             {},
-            m.fields.List(
+            mr.fields.ListField(
                 m.fields.Nested(EMPTY_SCHEMA, allow_none=True, **default_fields(None)),
                 allow_none=True,
                 **default_fields(None),
             ),
         ),
         # containers: Dict[str, Any]
-        (dict[str, Any], {}, m.fields.Dict(required=True)),
+        (dict[str, Any], {}, mr.fields.DictField(required=True)),
         (
             dict[str, Any],
             mr.metadata(name="i"),
-            m.fields.Dict(required=True, **data_key_fields("i")),
+            mr.fields.DictField(required=True, **data_key_fields("i")),
         ),
-        (Optional[dict[str, Any]], {}, m.fields.Dict(allow_none=True, **default_fields(None))),
+        (Optional[dict[str, Any]], {}, mr.fields.DictField(allow_none=True, **default_fields(None))),
         (
             Optional[dict[str, Any]],
             mr.metadata(name="i"),
-            m.fields.Dict(allow_none=True, **default_fields(None), **data_key_fields("i")),
+            mr.fields.DictField(allow_none=True, **default_fields(None), **data_key_fields("i")),
         ),
-        (dict[str, Any] | None, {}, m.fields.Dict(allow_none=True, **default_fields(None))),
+        (dict[str, Any] | None, {}, mr.fields.DictField(allow_none=True, **default_fields(None))),
         (
             dict[str, Any] | None,
             mr.metadata(name="i"),
-            m.fields.Dict(allow_none=True, **default_fields(None), **data_key_fields("i")),
+            mr.fields.DictField(allow_none=True, **default_fields(None), **data_key_fields("i")),
         ),
-        (Dict[str, Any], {}, m.fields.Dict(required=True)),
+        (Dict[str, Any], {}, mr.fields.DictField(required=True)),
         (
             Dict[str, Any],
             mr.metadata(name="i"),
-            m.fields.Dict(required=True, **data_key_fields("i")),
+            mr.fields.DictField(required=True, **data_key_fields("i")),
         ),
-        (Optional[Dict[str, Any]], {}, m.fields.Dict(allow_none=True, **default_fields(None))),
+        (Optional[Dict[str, Any]], {}, mr.fields.DictField(allow_none=True, **default_fields(None))),
         (
             Optional[Dict[str, Any]],
             mr.metadata(name="i"),
-            m.fields.Dict(allow_none=True, **default_fields(None), **data_key_fields("i")),
+            mr.fields.DictField(allow_none=True, **default_fields(None), **data_key_fields("i")),
         ),
-        (Dict[str, Any] | None, {}, m.fields.Dict(allow_none=True, **default_fields(None))),
+        (Dict[str, Any] | None, {}, mr.fields.DictField(allow_none=True, **default_fields(None))),
         (
             Dict[str, Any] | None,
             mr.metadata(name="i"),
-            m.fields.Dict(allow_none=True, **default_fields(None), **data_key_fields("i")),
+            mr.fields.DictField(allow_none=True, **default_fields(None), **data_key_fields("i")),
         ),
     ],
 )
@@ -0,0 +1,123 @@
+import dataclasses
+import datetime
+import decimal
+import enum
+import uuid
+from typing import Any
+
+import marshmallow_recipe as mr
+
+
+class Color(str, enum.Enum):
+    RED = "RED"
+    GREEN = "GREEN"
+
+    def __str__(self) -> str:
+        return self.value
+
+
+@dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+class Nested:
+    str_field: str
+
+
+@dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+class Container:
+    str_field: str
+    int_field: int = dataclasses.field(metadata=mr.metadata(name="i"))
+    float_field: float
+    bool_field: bool
+    decimal_field: decimal.Decimal
+    date_field: datetime.date
+    datetime_field: datetime.datetime
+    uuid_field: uuid.UUID
+    enum_field: Color = dataclasses.field(metadata=mr.enum_metadata(aliases={"green": Color.GREEN}))
+    nested_field: Nested
+    list_field: list[int]
+    dict_field: dict[str, Any]
+    any_field: Any
+    optional_str_field: str | None = None
+    optional_nested_field: Nested | None = None
+
+
+def test_json_schema() -> None:
+    json_schema = mr.json_schema(Container)
+
+    assert sorted(json_schema.pop("required")) == sorted(
+        [
+            "str_field",
+            "i",
+            "float_field",
+            "bool_field",
+            "decimal_field",
+            "date_field",
+            "datetime_field",
+            "uuid_field",
+            "enum_field",
+            "nested_field",
+            "list_field",
+            "dict_field",
+        ]
+    )
+    assert json_schema == {
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "type": "object",
+        "properties": {
+            "str_field": {"type": "string"},
+            "i": {"type": "integer"},
+            "float_field": {"type": "number"},
+            "bool_field": {"type": "boolean"},
+            "decimal_field": {"type": "string"},
+            "date_field": {"type": "string", "format": "date"},
+            "datetime_field": {"type": "string", "format": "date-time"},
+            "uuid_field": {"type": "string", "format": "uuid"},
+            "enum_field": {"type": "string", "enum": ["RED", "green", "GREEN"]},
+            "nested_field": {
+                "type": "object",
+                "properties": {"str_field": {"type": "string"}},
+                "required": ["str_field"],
+            },
+            "list_field": {"type": "array", "items": {"type": "integer"}},
+            "dict_field": {"type": "object"},
+            "any_field": {},
+            "optional_str_field": {"anyOf": [{"type": "string"}, {"type": "null"}]},
+            "optional_nested_field": {
+                "anyOf": [
+                    {"type": "object", "properties": {"str_field": {"type": "string"}}, "required": ["str_field"]},
+                    {"type": "null"},
+                ]
+            },
+        },
+    }
+
+
+def test_json_schema_naming_case() -> None:
+    assert mr.json_schema(Nested, naming_case=mr.CAMEL_CASE) == {
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "type": "object",
+        "properties": {"strField": {"type": "string"}},
+        "required": ["strField"],
+    }
+
+
+def test_json_schema_dict_entries() -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class DictContainer:
+        dict_field: dict[int, str] = dataclasses.field(metadata=mr.dict_metadata(entries=True))
+
+    assert mr.json_schema(DictContainer)["properties"] == {
+        "dict_field": {
+            "anyOf": [
+                {"type": "object", "additionalProperties": {"type": "string"}},
+                {
+                    "type": "array",
+                    "items": {
+                        "type": "array",
+                        "items": [{"type": "integer"}, {"type": "string"}],
+                        "minItems": 2,
+                        "maxItems": 2,
+                    },
+                },
+            ]
+        }
+    }
@@ -4,6 +4,8 @@ import decimal
 import enum
 import uuid
 
+import marshmallow as m
+
 import marshmallow_recipe as mr
 
 
@@ -322,3 +324,17 @@ def test_nested_with_missing() -> None:
     dumped = mr.dump(filled_data)
     loaded = mr.load(TypeWithNested, dumped)
     assert loaded == filled_data
+
+
+def test_marshmallow_missing_is_skipped() -> None:
+    assert mr.MISSING is m.missing
+
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class TypeWithMissing:
+        str_field: str = m.missing
+        int_field: int = m.missing
+
+    dumped = mr.dump(TypeWithMissing(int_field=1))
+    assert dumped == {"int_field": 1}
+    loaded = mr.load(TypeWithMissing, dumped)
+    assert loaded == TypeWithMissing(int_field=1)
@@ -1,9 +1,14 @@
+import collections
 import dataclasses
 import datetime
 import decimal
 import enum
+import fractions
+import math
+import pathlib
 import uuid
-from typing import Any, cast
+import zoneinfo
+from typing import Any, Iterator, Mapping, NamedTuple, TypedDict, cast
 
 import marshmallow as m
 import pytest
@@ -253,6 +258,12 @@ def test_many_empty():
     [
         ("2022-02-20T11:33:48.607289+00:00", datetime.datetime(2022, 2, 20, 11, 33, 48, 607289, datetime.timezone.utc)),
         ("2022-02-20T11:33:48.607289", datetime.datetime(2022, 2, 20, 11, 33, 48, 607289, datetime.timezone.utc)),
+        ("2022-02-20T11:33:48+03:00", datetime.datetime(2022, 2, 20, 8, 33, 48, 0, datetime.timezone.utc)),
+        ("2022-02-20T11:33:48+0300", datetime.datetime(2022, 2, 20, 8, 33, 48, 0, datetime.timezone.utc)),
+        ("2022-02-20T11:33:48+03", datetime.datetime(2022, 2, 20, 8, 33, 48, 0, datetime.timezone.utc)),
+        ("2022-02-20T11:33:48-0530", datetime.datetime(2022, 2, 20, 17, 3, 48, 0, datetime.timezone.utc)),
+        ("2022-02-20T11:33:48.6072891Z", datetime.datetime(2022, 2, 20, 11, 33, 48, 607289, datetime.timezone.utc)),
+        ("2022-02-20T11:33:48.607289123Z", datetime.datetime(2022, 2, 20, 11, 33, 48, 607289, datetime.timezone.utc)),
     ],
 )
 def test_datetime_field_load(raw: str, dt: datetime.datetime) -> None:
@@ -264,6 +275,26 @@ def test_datetime_field_load(raw: str, dt: datetime.datetime) -> None:
     assert loaded == DateTimeContainer(datetime_field=dt)
 
 
+@pytest.mark.parametrize(
+    "raw",
+    [
+        "2022-02-20T11:33:48+99:00",
+        "2022-02-20T11:33:48+03:75",
+        "2022-02-20T11:33:48+9999",
+        "2022-02-20T11:33:48+0375",
+        "2022-02-20T11:33:48-99",
+    ],
+)
+def test_datetime_field_load_out_of_range_offset(raw: str) -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class DateTimeContainer:
+        datetime_field: datetime.datetime
+
+    with pytest.raises(m.ValidationError) as exc_info:
+        mr.load(DateTimeContainer, dict(datetime_field=raw))
+    assert exc_info.value.messages == {"datetime_field": ["Not a valid datetime."]}
+
+
 @pytest.mark.parametrize(
     "dt, raw",
     [
@@ -352,3 +383,1528 @@ def test_naming_case_in_options() -> None:
 
     dumped = mr.dump(TestFieldContainer(test_field="some_value"))
     assert dumped == {"testField": "some_value"}
+
+
+def test_unregister() -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class BoolContainer:
+        bool_field: bool
+
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class IntContainer:
+        int_field: int
+
+    bool_schema = mr.schema(BoolContainer)
+    bool_many_schema = mr.schema(BoolContainer, many=True)
+    int_schema = mr.schema(IntContainer)
+
+    mr.unregister(BoolContainer)
+
+    assert mr.schema(BoolContainer) is not bool_schema
+    assert mr.schema(BoolContainer, many=True) is not bool_many_schema
+    assert mr.schema(IntContainer) is int_schema
+
+
+def test_clear_cache() -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class BoolContainer:
+        bool_field: bool
+
+    bool_schema = mr.schema(BoolContainer)
+
+    mr.clear_cache()
+
+    assert mr.schema(BoolContainer) is not bool_schema
+    assert mr.load(BoolContainer, dict(bool_field=True)) == BoolContainer(bool_field=True)
+
+
+def test_warm() -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class Leaf:
+        str_field: str
+
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class Branch:
+        leaves: list[Leaf]
+        leaf_by_name: dict[str, Leaf]
+        optional_leaf: Leaf | None = None
+
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class Tree:
+        branch: Branch
+
+    mr.clear_cache()
+
+    mr.warm(Tree)
+
+    assert mr.registered_classes() == {Tree, Branch, Leaf}
+    leaf_schema = mr.schema(Leaf)
+    mr.warm(Tree)
+    assert mr.schema(Leaf) is leaf_schema
+
+
+@pytest.mark.parametrize(
+    "value, raw",
+    [
+        (2.675, 2.68),
+        (0.125, 0.12),
+        (0.135, 0.14),
+        (1.005, 1.0),
+        (0.1 + 0.2, 0.3),
+        (42.0, 42.0),
+    ],
+)
+def test_float_field_dump_with_places(value: float, raw: float) -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class FloatContainer:
+        float_field: float = dataclasses.field(metadata=mr.float_metadata(places=2))
+
+    dumped = mr.dump(FloatContainer(float_field=value))
+    assert dumped == dict(float_field=raw)
+
+
+def test_digit_field_name() -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class IntContainer:
+        int_field: int = dataclasses.field(metadata=mr.metadata(name="2024"))
+
+    loaded = mr.load(IntContainer, {"2024": 42})
+    assert loaded == IntContainer(int_field=42)
+    assert mr.dump(loaded) == {"2024": 42}
+
+    with pytest.raises(m.ValidationError) as exc_info:
+        mr.load(IntContainer, {"2024": "invalid"})
+
+    assert exc_info.value.messages == {"2024": ["Not a valid integer."]}
+
+
+def test_dict_with_digit_keys() -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class DictContainer:
+        dict_field: dict[str, Any]
+
+    raw = dict(dict_field={"2024": 42, "1": "one"})
+    loaded = mr.load(DictContainer, raw)
+
+    assert loaded == DictContainer(dict_field={"2024": 42, "1": "one"})
+    assert mr.dump(loaded) == raw
+
+
+def test_enum_field_aliases() -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class EnumContainer:
+        enum_field: Parity = dataclasses.field(metadata=mr.enum_metadata(aliases={"O": Parity.ODD}))
+
+    assert mr.dump(EnumContainer(enum_field=Parity.ODD)) == dict(enum_field="O")
+    assert mr.dump(EnumContainer(enum_field=Parity.EVEN)) == dict(enum_field="even")
+    assert mr.load(EnumContainer, dict(enum_field="O")) == EnumContainer(enum_field=Parity.ODD)
+    assert mr.load(EnumContainer, dict(enum_field="even")) == EnumContainer(enum_field=Parity.EVEN)
+
+    with pytest.raises(m.ValidationError) as exc_info:
+        mr.load(EnumContainer, dict(enum_field="E"))
+
+    assert exc_info.value.messages == {"enum_field": ["Not a valid choice: 'E'. Allowed values: ['O', 'odd', 'even']"]}
+
+
+def test_list_with_optional_items() -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class ListContainer:
+        list_field: list[int | None]
+        nested_list_field: list[list[str | None]]
+
+    raw = dict(list_field=[1, None, 3], nested_list_field=[["a", None], [None]])
+    loaded = mr.load(ListContainer, raw)
+
+    assert loaded == ListContainer(list_field=[1, None, 3], nested_list_field=[["a", None], [None]])
+    assert mr.dump(loaded) == raw
+
+
+def test_list_with_required_items() -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class ListContainer:
+        list_field: list[int]
+
+    with pytest.raises(m.ValidationError):
+        mr.load(ListContainer, dict(list_field=[1, None]))
+
+
+@pytest.mark.parametrize(
+    "raw, dt",
+    [
+        ("2022-02-20 11:33:48", datetime.datetime(2022, 2, 20, 11, 33, 48, 0, datetime.timezone.utc)),
+        ("2022-02-20T11:33:48+03:00", datetime.datetime(2022, 2, 20, 8, 33, 48, 0, datetime.timezone.utc)),
+    ],
+)
+def test_datetime_field_load_with_formats(raw: str, dt: datetime.datetime) -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class DateTimeContainer:
+        datetime_field: datetime.datetime = dataclasses.field(
+            metadata=mr.datetime_metadata(formats=["%Y-%m-%d %H:%M:%S", "%Y-%m-%dT%H:%M:%S%z"])
+        )
+
+    loaded = mr.load(DateTimeContainer, dict(datetime_field=raw))
+    assert loaded == DateTimeContainer(datetime_field=dt)
+
+
+def test_datetime_field_with_formats() -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class DateTimeContainer:
+        datetime_field: datetime.datetime = dataclasses.field(
+            metadata=mr.datetime_metadata(formats=["%Y-%m-%d %H:%M:%S", "%Y-%m-%dT%H:%M:%S%z"])
+        )
+
+    dumped = mr.dump(DateTimeContainer(datetime_field=datetime.datetime(2022, 2, 20, 11, 33, 48)))
+    assert dumped == dict(datetime_field="2022-02-20 11:33:48")
+
+    with pytest.raises(m.ValidationError) as exc_info:
+        mr.load(DateTimeContainer, dict(datetime_field="20/02/2022"))
+
+    assert exc_info.value.messages == {"datetime_field": ["Not a valid datetime."]}
+
+
+@pytest.mark.parametrize(
+    "timestamp_unit, raw, value",
+    [
+        (mr.TimestampUnit.DAYS, 19043, datetime.date(2022, 2, 20)),
+        (mr.TimestampUnit.DAYS, "2022-02-20", datetime.date(2022, 2, 20)),
+        (mr.TimestampUnit.SECONDS, 1645356828, datetime.date(2022, 2, 20)),
+        (mr.TimestampUnit.SECONDS, 1645356828.607289, datetime.date(2022, 2, 20)),
+        (mr.TimestampUnit.SECONDS, "2022-02-20", datetime.date(2022, 2, 20)),
+    ],
+)
+def test_date_field_load_from_timestamp(timestamp_unit: mr.TimestampUnit, raw: Any, value: datetime.date) -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class DateContainer:
+        date_field: datetime.date = dataclasses.field(metadata=mr.date_metadata(timestamp_unit=timestamp_unit))
+
+    loaded = mr.load(DateContainer, dict(date_field=raw))
+    assert loaded == DateContainer(date_field=value)
+
+
+@pytest.mark.parametrize(
+    "timestamp_unit, raw",
+    [
+        (None, 19043),
+        (mr.TimestampUnit.DAYS, 10**10),
+        (mr.TimestampUnit.SECONDS, 10**20),
+    ],
+)
+def test_date_field_load_invalid_timestamp(timestamp_unit: mr.TimestampUnit | None, raw: Any) -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class DateContainer:
+        date_field: datetime.date = dataclasses.field(metadata=mr.date_metadata(timestamp_unit=timestamp_unit))
+
+    with pytest.raises(m.ValidationError) as exc_info:
+        mr.load(DateContainer, dict(date_field=raw))
+
+    assert exc_info.value.messages == {"date_field": ["Not a valid date."]}
+
+
+def test_format_spec() -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class FormattedContainer:
+        float_field: float = dataclasses.field(metadata=mr.metadata(format_spec=".2f"))
+        int_field: int = dataclasses.field(metadata=mr.metadata(name="IntField", format_spec="05d"))
+        optional_int_field: int | None = dataclasses.field(default=None, metadata=mr.metadata(format_spec="05d"))
+
+    dumped = mr.dump(FormattedContainer(float_field=3.14159, int_field=42))
+    assert dumped == dict(float_field="3.14", IntField="00042")
+
+    loaded = mr.load(FormattedContainer, dumped)
+    assert loaded == FormattedContainer(float_field=3.14, int_field=42)
+
+
+def test_format_spec_with_strftime() -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class DateContainer:
+        date_field: datetime.date = dataclasses.field(metadata=mr.metadata(format_spec="%d/%m/%Y"))
+
+    assert mr.dump(DateContainer(date_field=datetime.date(2022, 2, 20))) == dict(date_field="20/02/2022")
+
+
+def test_dump_validate() -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class Order:
+        total: int = dataclasses.field(metadata=mr.metadata(dump_validate=lambda x: x >= 0))
+
+    assert mr.dump(Order(total=42)) == dict(total=42)
+    assert mr.load(Order, dict(total=-1)) == Order(total=-1)
+
+    with pytest.raises(m.ValidationError) as exc_info:
+        mr.dump(Order(total=-1))
+
+    assert exc_info.value.messages == {"total": ["Invalid value."]}
+
+
+def test_path_field() -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class PathContainer:
+        path_field: pathlib.Path
+        pure_posix_path_field: pathlib.PurePosixPath
+        optional_path_field: pathlib.Path | None = None
+
+    raw = dict(path_field="etc/config.yaml", pure_posix_path_field="/var/log")
+    loaded = mr.load(PathContainer, raw)
+
+    assert loaded == PathContainer(
+        path_field=pathlib.Path("etc/config.yaml"), pure_posix_path_field=pathlib.PurePosixPath("/var/log")
+    )
+    assert type(loaded.pure_posix_path_field) is pathlib.PurePosixPath
+    assert mr.dump(loaded) == raw
+
+
+def test_path_field_invalid() -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class PathContainer:
+        path_field: pathlib.Path
+
+    with pytest.raises(m.ValidationError) as exc_info:
+        mr.load(PathContainer, dict(path_field=42))
+
+    assert exc_info.value.messages == {"path_field": ["Not a valid path."]}
+
+    with pytest.raises(m.ValidationError) as exc_info:
+        mr.dump(PathContainer(path_field=cast(pathlib.Path, 42)))
+
+    assert exc_info.value.messages == {"path_field": ["Not a valid path."]}
+
+
+def test_none_value_handling_per_field() -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class Container:
+        ignored: int | None = None
+        included: int | None = dataclasses.field(
+            default=None, metadata=mr.metadata(none_value_handling=mr.NoneValueHandling.INCLUDE)
+        )
+
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    @mr.options(none_value_handling=mr.NoneValueHandling.INCLUDE)
+    class IncludeContainer:
+        included: int | None = None
+        ignored: int | None = dataclasses.field(
+            default=None, metadata=mr.metadata(name="i", none_value_handling=mr.NoneValueHandling.IGNORE)
+        )
+
+    assert mr.dump(Container()) == {"included": None}
+    assert mr.dump(Container(ignored=1, included=2)) == {"ignored": 1, "included": 2}
+    assert mr.dump(IncludeContainer()) == {"included": None}
+    assert mr.dump(IncludeContainer(ignored=1)) == {"included": None, "i": 1}
+
+
+def test_strip_all_strings() -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class Nested:
+        str_field: str
+
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    @mr.options(strip_all_strings=True)
+    class Container:
+        str_field: str
+        optional_str_field: str | None = None
+        nested: Nested
+        list_field: list[str]
+        dict_field: dict[str, Any]
+
+    loaded = mr.load(
+        Container,
+        dict(
+            str_field=" value ",
+            nested=dict(str_field="\tnested\n"),
+            list_field=[" a", "b "],
+            dict_field={"key": " value ", "nested": {"key": " value "}, "int": 1},
+        ),
+    )
+
+    assert loaded == Container(
+        str_field="value",
+        nested=Nested(str_field="nested"),
+        list_field=["a", "b"],
+        dict_field={"key": "value", "nested": {"key": "value"}, "int": 1},
+    )
+    assert mr.load(Nested, dict(str_field=" value ")) == Nested(str_field=" value ")
+
+
+def test_strip_all_strings_deeply_nested() -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    @mr.options(strip_all_strings=True)
+    class Container:
+        any_field: Any
+
+    raw: Any = " value "
+    for _ in range(10000):
+        raw = [raw]
+
+    loaded = mr.load(Container, dict(any_field=raw))
+
+    value = loaded.any_field
+    for _ in range(10000):
+        value = value[0]
+    assert value == "value"
+
+
+def test_intern_strings() -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    @mr.options(intern_strings=True)
+    class Container:
+        str_field: str
+        list_field: list[str]
+
+    # strings built at runtime are distinct objects unless interned
+    first, second = "".join(["stat", "us"]), "".join(["sta", "tus"])
+    assert first is not second
+
+    loaded = mr.load(Container, dict(str_field=first, list_field=[second]))
+
+    assert loaded == Container(str_field="status", list_field=["status"])
+    assert loaded.str_field is loaded.list_field[0]
+
+
+def test_intern_strings_deeply_nested() -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    @mr.options(intern_strings=True)
+    class Container:
+        str_field: str
+        any_field: Any
+
+    first, second = "".join(["stat", "us"]), "".join(["sta", "tus"])
+    raw: Any = second
+    for _ in range(10000):
+        raw = [raw]
+
+    loaded = mr.load(Container, dict(str_field=first, any_field=raw))
+
+    value = loaded.any_field
+    for _ in range(10000):
+        value = value[0]
+    assert value is loaded.str_field
+
+
+def test_deeply_nested_values() -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    @mr.options(strip_all_strings=True, intern_strings=True)
+    class Container:
+        any_field: Any
+        dict_field: dict[str, Any]
+
+    raw: Any = " value "
+    for _ in range(10000):
+        raw = {"key": [raw]}
+
+    loaded = mr.load(Container, dict(any_field=raw, dict_field=raw))
+    dumped = mr.dump(loaded)
+
+    for value in (loaded.any_field, loaded.dict_field, dumped["any_field"], dumped["dict_field"]):
+        for _ in range(10000):
+            value = value["key"][0]
+        assert value == "value"
+
+
+@pytest.mark.parametrize(
+    "value, expected",
+    [
+        (decimal.Decimal("0.00001"), "0.00001"),
+        (decimal.Decimal("1E-10"), "0.0000000001"),
+        (decimal.Decimal("1.5E+20"), "150000000000000000000"),
+        (decimal.Decimal("42"), "42"),
+    ],
+)
+def test_decimal_field_without_places_is_dumped_plain(value: decimal.Decimal, expected: str) -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class DecimalContainer:
+        decimal_field: decimal.Decimal = dataclasses.field(metadata=mr.decimal_metadata(places=None))
+
+    dumped = mr.dump(DecimalContainer(decimal_field=value))
+
+    assert dumped == {"decimal_field": expected}
+    assert mr.load(DecimalContainer, dumped) == DecimalContainer(decimal_field=value)
+
+
+def test_strict_types() -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    @mr.options(strict_types=True)
+    class Container:
+        int_field: int
+        float_field: float
+        bool_field: bool
+        str_field: str
+        optional_int_field: int | None = None
+
+    assert mr.load(Container, dict(int_field=1, float_field=1.5, bool_field=True, str_field="1")) == Container(
+        int_field=1, float_field=1.5, bool_field=True, str_field="1"
+    )
+
+    with pytest.raises(m.ValidationError) as exc_info:
+        mr.load(Container, dict(int_field=1.0, float_field=1, bool_field=1, str_field=1, optional_int_field="1"))
+
+    assert exc_info.value.messages == {
+        "int_field": ["Not a valid integer."],
+        "float_field": ["Not a valid number."],
+        "bool_field": ["Not a valid boolean."],
+        "str_field": ["Not a valid string."],
+        "optional_int_field": ["Not a valid integer."],
+    }
+
+    with pytest.raises(m.ValidationError) as exc_info:
+        mr.load(Container, dict(int_field=True, float_field="1.5", bool_field="true", str_field="1"))
+
+    assert exc_info.value.messages == {
+        "int_field": ["Not a valid integer."],
+        "float_field": ["Not a valid number."],
+        "bool_field": ["Not a valid boolean."],
+    }
+
+
+def test_float_field_allow_nan() -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class FloatContainer:
+        float_field: float = dataclasses.field(metadata=mr.float_metadata(allow_nan=True))
+
+    for value in ("NaN", "Infinity", "-Infinity"):
+        loaded = mr.load(FloatContainer, dict(float_field=float(value)))
+        assert str(mr.dump(loaded)["float_field"]) == str(float(value))
+
+    assert math.isnan(mr.load(FloatContainer, dict(float_field="NaN")).float_field)
+    assert mr.dump(FloatContainer(float_field=float("-inf"))) == dict(float_field=float("-inf"))
+
+
+def test_float_field_rejects_nan_by_default() -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class FloatContainer:
+        float_field: float
+
+    for value in (float("nan"), float("inf"), "-Infinity"):
+        with pytest.raises(m.ValidationError) as exc_info:
+            mr.load(FloatContainer, dict(float_field=value))
+        assert exc_info.value.messages == {
+            "float_field": ["Special numeric values (nan or infinity) are not permitted."]
+        }
+
+
+def test_complex_field() -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class ComplexContainer:
+        complex_field: complex
+        optional_complex_field: complex | None = None
+
+    raw = dict(complex_field=dict(real=1.5, imag=-2.0))
+    loaded = mr.load(ComplexContainer, raw)
+
+    assert loaded == ComplexContainer(complex_field=complex(1.5, -2))
+    assert mr.dump(loaded) == raw
+    assert mr.load(ComplexContainer, dict(complex_field=dict(real=0, imag=1))) == ComplexContainer(complex_field=1j)
+
+
+@pytest.mark.parametrize("value", [dict(real=1.0), dict(imag=1.0), dict(real="1", imag=1.0), [1.0, 2.0], "1+2j"])
+def test_complex_field_invalid(value: Any) -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class ComplexContainer:
+        complex_field: complex
+
+    with pytest.raises(m.ValidationError) as exc_info:
+        mr.load(ComplexContainer, dict(complex_field=value))
+
+    assert exc_info.value.messages == {"complex_field": ["Not a valid complex number."]}
+
+
+def test_all_optional() -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class Nested:
+        str_field: str
+
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    @mr.options(all_optional=True)
+    class Patch:
+        int_field: int
+        str_field: str = dataclasses.field(metadata=mr.metadata(validate=lambda x: len(x) > 0))
+        optional_str_field: str | None = None
+        nested: Nested
+        list_field: list[str]
+        dict_field: dict[str, Any]
+
+    empty = Patch(
+        int_field=mr.MISSING,
+        str_field=mr.MISSING,
+        nested=mr.MISSING,
+        list_field=mr.MISSING,
+        dict_field=mr.MISSING,
+    )
+    assert mr.load(Patch, {}) == empty
+    assert mr.dump(empty) == {}
+
+    partial = mr.load(Patch, dict(str_field="value", nested=dict(str_field="nested")))
+    assert partial == dataclasses.replace(empty, str_field="value", nested=Nested(str_field="nested"))
+    assert mr.dump(partial) == dict(str_field="value", nested=dict(str_field="nested"))
+
+    with pytest.raises(m.ValidationError) as exc_info:
+        mr.load(Patch, dict(int_field=None, str_field=""))
+
+    assert exc_info.value.messages == {"int_field": ["Field may not be null."], "str_field": ["Invalid value."]}
+
+
+def test_dump_default() -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class Container:
+        str_field: str = dataclasses.field(default=mr.MISSING, metadata=mr.metadata(dump_default="unknown"))
+        int_field: int = dataclasses.field(metadata=mr.metadata(name="i", dump_default=0))
+
+    assert mr.dump(Container(int_field=1)) == {"str_field": "unknown", "i": 1}
+    assert mr.dump(Container(str_field="value", int_field=1)) == {"str_field": "value", "i": 1}
+    assert mr.load(Container, {"i": 1}) == Container(int_field=1)
+
+    partial = object.__new__(Container)
+    assert mr.dump(partial) == {"str_field": "unknown", "i": 0}
+
+
+def test_typed_dict() -> None:
+    class Point(TypedDict):
+        x: int
+        y: int
+
+    class Labels(TypedDict, total=False):
+        name: str
+        color: str
+
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class Shape:
+        point: Point
+        labels: Labels
+        optional_point: Point | None = None
+
+    raw = dict(point=dict(x=1, y=2), labels=dict(name="origin"))
+    loaded = mr.load(Shape, raw)
+
+    assert loaded == Shape(point={"x": 1, "y": 2}, labels={"name": "origin"})
+    assert type(loaded.point) is dict
+    assert mr.dump(loaded) == raw
+    assert mr.load(Point, dict(x=1, y=2)) == {"x": 1, "y": 2}
+
+
+def test_typed_dict_invalid() -> None:
+    class Point(TypedDict):
+        x: int
+        y: int
+
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class Shape:
+        point: Point
+
+    with pytest.raises(m.ValidationError) as exc_info:
+        mr.load(Shape, dict(point=dict(x="invalid")))
+
+    assert exc_info.value.messages == {
+        "point": {"x": ["Not a valid integer."], "y": ["Missing data for required field."]}
+    }
+
+
+def test_non_init_fields_are_set_on_load() -> None:
+    @dataclasses.dataclass(slots=True, kw_only=True)
+    class Container:
+        values: list[int]
+        total: int = dataclasses.field(init=False, default=0)
+        version: int = dataclasses.field(init=False, default=1)
+        history: list[int] = dataclasses.field(init=False, default_factory=list)
+
+        def __post_init__(self) -> None:
+            self.total = sum(self.values)
+
+    loaded = mr.load(Container, dict(values=[1, 2, 3], total=42, version=42))
+
+    assert loaded.values == [1, 2, 3]
+    assert loaded.total == 6
+    assert loaded.version == 1
+    assert loaded.history == []
+    assert mr.dump(loaded) == dict(values=[1, 2, 3])
+
+
+@pytest.mark.parametrize(
+    "value, expected",
+    [
+        ("2024-W05-3", datetime.date(2024, 1, 31)),
+        ("2020-W53-7", datetime.date(2021, 1, 3)),
+        ("2024-123", datetime.date(2024, 5, 2)),
+        ("2024-366", datetime.date(2024, 12, 31)),
+        ("2024-05-02", datetime.date(2024, 5, 2)),
+    ],
+)
+def test_date_field_load_iso_week_and_ordinal(value: str, expected: datetime.date) -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class DateContainer:
+        date_field: datetime.date = dataclasses.field(metadata=mr.date_metadata(iso_week_and_ordinal=True))
+
+    loaded = mr.load(DateContainer, dict(date_field=value))
+
+    assert loaded == DateContainer(date_field=expected)
+    assert mr.dump(loaded) == dict(date_field=expected.isoformat())
+
+
+@pytest.mark.parametrize(
+    "value, iso_week_and_ordinal",
+    [("2024-W54-1", True), ("2023-366", True), ("2024-000", True), ("2024-W05-3", False), ("2024-123", False)],
+)
+def test_date_field_load_invalid_iso_week_and_ordinal(value: str, iso_week_and_ordinal: bool) -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class DateContainer:
+        date_field: datetime.date = dataclasses.field(
+            metadata=mr.date_metadata(iso_week_and_ordinal=iso_week_and_ordinal)
+        )
+
+    with pytest.raises(m.ValidationError) as exc_info:
+        mr.load(DateContainer, dict(date_field=value))
+
+    assert exc_info.value.messages == {"date_field": ["Not a valid date."]}
+
+
+@pytest.mark.parametrize(
+    "uuid_format, uppercase, expected",
+    [
+        (mr.UuidFormat.HYPHENATED, False, "d0c6b1a8-9c3e-4f5b-8a7d-2e1f0b9c8d7e"),
+        (mr.UuidFormat.HYPHENATED, True, "D0C6B1A8-9C3E-4F5B-8A7D-2E1F0B9C8D7E"),
+        (mr.UuidFormat.SIMPLE, False, "d0c6b1a89c3e4f5b8a7d2e1f0b9c8d7e"),
+        (mr.UuidFormat.SIMPLE, True, "D0C6B1A89C3E4F5B8A7D2E1F0B9C8D7E"),
+        (mr.UuidFormat.URN, False, "urn:uuid:d0c6b1a8-9c3e-4f5b-8a7d-2e1f0b9c8d7e"),
+        (mr.UuidFormat.URN, True, "urn:uuid:D0C6B1A8-9C3E-4F5B-8A7D-2E1F0B9C8D7E"),
+    ],
+)
+def test_uuid_field_format(uuid_format: mr.UuidFormat, uppercase: bool, expected: str) -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class UuidContainer:
+        uuid_field: uuid.UUID = dataclasses.field(
+            metadata=mr.uuid_metadata(uuid_format=uuid_format, uppercase=uppercase)
+        )
+
+    value = uuid.UUID("d0c6b1a8-9c3e-4f5b-8a7d-2e1f0b9c8d7e")
+
+    assert mr.dump(UuidContainer(uuid_field=value)) == dict(uuid_field=expected)
+    assert mr.load(UuidContainer, dict(uuid_field=expected)) == UuidContainer(uuid_field=value)
+
+
+def test_numeric_fields_with_thousands_separator() -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class NumericContainer:
+        int_field: int = dataclasses.field(metadata=mr.int_metadata(thousands_separator=","))
+        float_field: float = dataclasses.field(metadata=mr.float_metadata(thousands_separator=" "))
+        decimal_field: decimal.Decimal = dataclasses.field(metadata=mr.decimal_metadata(thousands_separator=","))
+
+    loaded = mr.load(NumericContainer, dict(int_field="-1,234,567", float_field="1 234.5", decimal_field="1,234.56"))
+
+    assert loaded == NumericContainer(int_field=-1234567, float_field=1234.5, decimal_field=decimal.Decimal("1234.56"))
+    assert mr.dump(loaded) == dict(int_field=-1234567, float_field=1234.5, decimal_field="1234.56")
+    assert mr.load(NumericContainer, dict(int_field=1, float_field="1.5", decimal_field="123.45")) == NumericContainer(
+        int_field=1, float_field=1.5, decimal_field=decimal.Decimal("123.45")
+    )
+
+
+@pytest.mark.parametrize("value", ["1,2,3", "1234,567", ",123", "1,234,56", "1,234.5,6", "1,,234"])
+def test_decimal_field_with_malformed_thousands_separator(value: str) -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class DecimalContainer:
+        decimal_field: decimal.Decimal = dataclasses.field(metadata=mr.decimal_metadata(thousands_separator=","))
+
+    with pytest.raises(m.ValidationError) as exc_info:
+        mr.load(DecimalContainer, dict(decimal_field=value))
+
+    assert exc_info.value.messages == {"decimal_field": ["Not a valid number."]}
+
+
+def test_pre_load() -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class Container:
+        optional_int_field: int | None = dataclasses.field(
+            default=None, metadata=mr.metadata(pre_load=lambda x: None if x == "" else x)
+        )
+        str_field: str = dataclasses.field(
+            default=mr.MISSING, metadata=mr.metadata(name="s", pre_load=lambda x: mr.MISSING if x == "N/A" else x)
+        )
+        legacy_bool_field: bool = dataclasses.field(metadata=mr.metadata(pre_load=lambda x: {"Y": True, "N": False}[x]))
+
+    assert mr.load(Container, dict(optional_int_field="", s="N/A", legacy_bool_field="Y")) == Container(
+        legacy_bool_field=True
+    )
+    assert mr.load(Container, dict(optional_int_field="42", s="value", legacy_bool_field="N")) == Container(
+        optional_int_field=42, str_field="value", legacy_bool_field=False
+    )
+    assert mr.dump(Container(str_field="value", legacy_bool_field=True)) == dict(s="value", legacy_bool_field=True)
+
+
+def test_schema_pre_load_and_post_load() -> None:
+    def rename_legacy_fields(data: dict[str, Any]) -> dict[str, Any]:
+        if "from" not in data:
+            return data
+        return {("start" if key == "from" else key): value for key, value in data.items()}
+
+    def check_range(loaded: Any) -> Any:
+        if loaded.start > loaded.end:
+            raise m.ValidationError("start must not be greater than end")
+        return dataclasses.replace(loaded, length=loaded.end - loaded.start)
+
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    @mr.options(pre_load=rename_legacy_fields, post_load=check_range)
+    class Range:
+        start: int
+        end: int
+        length: int = 0
+
+    assert mr.load(Range, dict(start=1, end=3)) == Range(start=1, end=3, length=2)
+    assert mr.load(Range, {"from": 2, "end": 3}) == Range(start=2, end=3, length=1)
+    assert mr.load_many(Range, [dict(start=1, end=1)]) == [Range(start=1, end=1, length=0)]
+    assert mr.dump(Range(start=1, end=3, length=2)) == dict(start=1, end=3, length=2)
+
+    with pytest.raises(m.ValidationError) as exc_info:
+        mr.load(Range, dict(start=3, end=1))
+
+    assert exc_info.value.messages == {"_schema": ["start must not be greater than end"]}
+
+
+def test_schema_pre_load_is_not_applied_on_dump() -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    @mr.options(pre_load=lambda data: data["payload"])
+    class Envelope:
+        value: int
+
+    assert mr.load(Envelope, dict(payload=dict(value=1))) == Envelope(value=1)
+    assert mr.dump(Envelope(value=1)) == dict(value=1)
+
+
+def test_schema_validate() -> None:
+    def validate_range(data: dict[str, Any]) -> bool:
+        if "start" in data and "end" in data and data["start"] > data["end"]:
+            raise m.ValidationError("start must not be greater than end")
+        return True
+
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    @mr.options(validate=validate_range)
+    class Range:
+        start: int
+        end: int
+        step: int = 1
+
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    @mr.options(validate=lambda data: data.get("step", 1) > 0)
+    class Steps:
+        step: int = 1
+
+    assert mr.load(Range, dict(start=1, end=3)) == Range(start=1, end=3)
+
+    with pytest.raises(m.ValidationError) as exc_info:
+        mr.load(Range, dict(start=3, end=1, step="invalid"))
+
+    assert exc_info.value.messages == {
+        "step": ["Not a valid integer."],
+        "_schema": ["start must not be greater than end"],
+    }
+
+    with pytest.raises(m.ValidationError) as exc_info:
+        mr.load(Steps, dict(step=0))
+
+    assert exc_info.value.messages == {"_schema": ["Invalid value."]}
+
+
+def test_schema_validate_is_not_applied_on_dump() -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    @mr.options(validate=lambda data: data["start"] <= data["end"])
+    class Range:
+        start: int
+        end: int
+
+    dumped = mr.dump(Range(start=3, end=1))
+
+    assert dumped == dict(start=3, end=1)
+    with pytest.raises(m.ValidationError) as exc_info:
+        mr.load(Range, dumped)
+    assert exc_info.value.messages == {"_schema": ["Invalid value."]}
+
+
+def test_float_field_precision_loss() -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class FloatContainer:
+        lenient_float_field: float = 0.0
+        float_field: float = dataclasses.field(default=0.0, metadata=mr.float_metadata(allow_precision_loss=False))
+
+    assert mr.load(FloatContainer, dict(lenient_float_field=9007199254740993)) == FloatContainer(
+        lenient_float_field=9007199254740992.0
+    )
+    assert mr.load(FloatContainer, dict(float_field=9007199254740992)) == FloatContainer(float_field=9007199254740992.0)
+    assert mr.load(FloatContainer, dict(float_field=-42)) == FloatContainer(float_field=-42.0)
+
+    for value in (9007199254740993, -9007199254740993, 10**400):
+        with pytest.raises(m.ValidationError) as exc_info:
+            mr.load(FloatContainer, dict(float_field=value))
+        assert exc_info.value.messages == {"float_field": ["Integer too large for float field."]}
+
+
+@pytest.mark.parametrize("value", [decimal.Decimal("NaN"), decimal.Decimal("Infinity"), decimal.Decimal("-Infinity")])
+def test_decimal_field_special_values(value: decimal.Decimal) -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class DecimalContainer:
+        decimal_field: decimal.Decimal
+
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class SpecialDecimalContainer:
+        decimal_field: decimal.Decimal = dataclasses.field(metadata=mr.decimal_metadata(allow_nan=True))
+
+    with pytest.raises(m.ValidationError) as exc_info:
+        mr.dump(DecimalContainer(decimal_field=value))
+    assert exc_info.value.messages == {"decimal_field": ["Decimal special values are not permitted."]}
+
+    with pytest.raises(m.ValidationError) as exc_info:
+        mr.load(DecimalContainer, dict(decimal_field=str(value)))
+    # marshmallow's message is kept on load
+    assert exc_info.value.messages == {"decimal_field": [m.fields.Decimal.default_error_messages["special"]]}
+
+    dumped = mr.dump(SpecialDecimalContainer(decimal_field=value))
+    assert dumped == dict(decimal_field=str(value))
+    assert str(mr.load(SpecialDecimalContainer, dumped).decimal_field) == str(value)
+
+
+@pytest.mark.parametrize(
+    "int_range, min_value, max_value",
+    [
+        (mr.IntRange.INT32, -(2**31), 2**31 - 1),
+        (mr.IntRange.UINT32, 0, 2**32 - 1),
+        (mr.IntRange.INT64, -(2**63), 2**63 - 1),
+        (mr.IntRange.UINT64, 0, 2**64 - 1),
+    ],
+)
+def test_int_field_range(int_range: mr.IntRange, min_value: int, max_value: int) -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class IntContainer:
+        int_field: int = dataclasses.field(metadata=mr.int_metadata(int_range=int_range))
+
+    assert mr.load(IntContainer, dict(int_field=min_value)) == IntContainer(int_field=min_value)
+    assert mr.load(IntContainer, dict(int_field=str(max_value))) == IntContainer(int_field=max_value)
+
+    for value in (min_value - 1, max_value + 1):
+        with pytest.raises(m.ValidationError) as exc_info:
+            mr.load(IntContainer, dict(int_field=value))
+        assert exc_info.value.messages == {"int_field": [f"Integer out of range for {str(int_range).lower()}."]}
+
+
+def test_datetime_field_require_tz() -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class DateTimeContainer:
+        datetime_field: datetime.datetime = dataclasses.field(metadata=mr.datetime_metadata(require_tz=True))
+
+    aware = DateTimeContainer(datetime_field=datetime.datetime(2022, 2, 20, 11, 33, 48, tzinfo=datetime.timezone.utc))
+    assert mr.load(DateTimeContainer, dict(datetime_field="2022-02-20T11:33:48+00:00")) == aware
+    assert mr.dump(aware) == dict(datetime_field="2022-02-20T11:33:48+00:00")
+
+    with pytest.raises(m.ValidationError) as exc_info:
+        mr.load(DateTimeContainer, dict(datetime_field="2022-02-20T11:33:48"))
+    assert exc_info.value.messages == {"datetime_field": ["Timezone-aware datetime required."]}
+
+    with pytest.raises(m.ValidationError) as exc_info:
+        mr.dump(DateTimeContainer(datetime_field=datetime.datetime(2022, 2, 20, 11, 33, 48)))
+    assert exc_info.value.messages == {"datetime_field": ["Timezone-aware datetime required."]}
+
+
+def test_datetime_field_require_naive() -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class DateTimeContainer:
+        datetime_field: datetime.datetime = dataclasses.field(metadata=mr.datetime_metadata(require_naive=True))
+
+    naive = DateTimeContainer(datetime_field=datetime.datetime(2022, 2, 20, 11, 33, 48))
+    assert mr.load(DateTimeContainer, dict(datetime_field="2022-02-20T11:33:48")) == naive
+    assert mr.dump(naive) == dict(datetime_field="2022-02-20T11:33:48")
+
+    with pytest.raises(m.ValidationError) as exc_info:
+        mr.load(DateTimeContainer, dict(datetime_field="2022-02-20T11:33:48+00:00"))
+    assert exc_info.value.messages == {"datetime_field": ["Naive datetime required."]}
+
+    with pytest.raises(m.ValidationError) as exc_info:
+        mr.dump(DateTimeContainer(datetime_field=datetime.datetime(2022, 2, 20, tzinfo=datetime.timezone.utc)))
+    assert exc_info.value.messages == {"datetime_field": ["Naive datetime required."]}
+
+
+def test_datetime_field_require_tz_and_naive_are_exclusive() -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class DateTimeContainer:
+        datetime_field: datetime.datetime = dataclasses.field(
+            metadata=mr.datetime_metadata(require_tz=True, require_naive=True)
+        )
+
+    with pytest.raises(ValueError):
+        mr.schema(DateTimeContainer)
+
+
+def test_datetime_field_default_tz() -> None:
+    moscow_tz = datetime.timezone(datetime.timedelta(hours=3))
+
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class DateTimeContainer:
+        datetime_field: datetime.datetime = dataclasses.field(metadata=mr.datetime_metadata(default_tz=moscow_tz))
+
+    utc = DateTimeContainer(datetime_field=datetime.datetime(2022, 2, 20, 8, 33, 48, tzinfo=datetime.timezone.utc))
+    assert mr.load(DateTimeContainer, dict(datetime_field="2022-02-20T11:33:48")) == utc
+    assert mr.load(DateTimeContainer, dict(datetime_field="2022-02-20T08:33:48+00:00")) == utc
+
+    naive = DateTimeContainer(datetime_field=datetime.datetime(2022, 2, 20, 11, 33, 48))
+    assert mr.load(DateTimeContainer, mr.dump(naive)) == utc
+
+
+def test_datetime_field_tz_name() -> None:
+    moscow_tz = zoneinfo.ZoneInfo("Europe/Moscow")
+
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class DateTimeContainer:
+        datetime_field: datetime.datetime = dataclasses.field(metadata=mr.datetime_metadata(tz_name=True))
+
+    moscow = DateTimeContainer(datetime_field=datetime.datetime(2022, 2, 20, 11, 33, 48, tzinfo=moscow_tz))
+    assert mr.dump(moscow) == dict(datetime_field="2022-02-20T11:33:48+03:00[Europe/Moscow]")
+
+    loaded = mr.load(DateTimeContainer, dict(datetime_field="2022-02-20T08:33:48+00:00[Europe/Moscow]"))
+    assert loaded == moscow
+    assert loaded.datetime_field.tzinfo == moscow_tz
+
+    utc = DateTimeContainer(datetime_field=datetime.datetime(2022, 2, 20, 8, 33, 48, tzinfo=datetime.timezone.utc))
+    assert mr.dump(utc) == dict(datetime_field="2022-02-20T08:33:48+00:00")
+    assert mr.load(DateTimeContainer, dict(datetime_field="2022-02-20T08:33:48+00:00")) == utc
+
+    with pytest.raises(m.ValidationError) as exc_info:
+        mr.load(DateTimeContainer, dict(datetime_field="2022-02-20T08:33:48+00:00[Mars/Olympus]"))
+    assert exc_info.value.messages == {"datetime_field": ["Unknown timezone 'Mars/Olympus'."]}
+
+
+@pytest.mark.parametrize(
+    "value, expected",
+    [
+        (decimal.Decimal("1.2300"), "1.23"),
+        (decimal.Decimal("100"), "100"),
+        (decimal.Decimal("1.0"), "1"),
+        (decimal.Decimal("0.000"), "0"),
+    ],
+)
+def test_decimal_field_normalize(value: decimal.Decimal, expected: str) -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class DecimalContainer:
+        decimal_field: decimal.Decimal = dataclasses.field(metadata=mr.decimal_metadata(places=None, normalize=True))
+
+    assert mr.dump(DecimalContainer(decimal_field=value)) == dict(decimal_field=expected)
+
+
+@pytest.mark.parametrize(
+    "value, expected",
+    [
+        (decimal.Decimal("1.50"), "1.5"),
+        (decimal.Decimal("1.234"), "1.23"),
+        (decimal.Decimal("100"), "100"),
+        (decimal.Decimal("0.001"), "0"),
+    ],
+)
+def test_decimal_field_normalize_with_default_places(value: decimal.Decimal, expected: str) -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class DecimalContainer:
+        decimal_field: decimal.Decimal = dataclasses.field(metadata=mr.decimal_metadata(normalize=True))
+
+    assert mr.dump(DecimalContainer(decimal_field=value)) == dict(decimal_field=expected)
+
+
+def test_bool_field_truthy_and_falsy() -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class BoolContainer:
+        bool_field: bool = dataclasses.field(metadata=mr.bool_metadata(truthy={"yes", True}, falsy={"no", False}))
+
+    assert mr.load(BoolContainer, dict(bool_field="yes")) == BoolContainer(bool_field=True)
+    assert mr.load(BoolContainer, dict(bool_field=False)) == BoolContainer(bool_field=False)
+    assert mr.dump(BoolContainer(bool_field=True)) == dict(bool_field=True)
+
+    assert mr.load(BoolContainer, dict(bool_field="YES")) == BoolContainer(bool_field=True)
+
+    with pytest.raises(m.ValidationError) as exc_info:
+        mr.load(BoolContainer, dict(bool_field="true"))
+    assert exc_info.value.messages == {"bool_field": ["Not a valid boolean."]}
+
+
+@pytest.mark.parametrize(
+    "value, expected",
+    [
+        (True, True),
+        (False, False),
+        ("true", True),
+        ("FALSE", False),
+        ("1", True),
+        ("0", False),
+        ("Yes", True),
+        ("no", False),
+        ("ON", True),
+        ("off", False),
+    ],
+)
+def test_bool_field_bool_strings(value: Any, expected: bool) -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class BoolContainer:
+        bool_field: bool = dataclasses.field(metadata=mr.bool_metadata(bool_strings=True))
+
+    assert mr.load(BoolContainer, dict(bool_field=value)) == BoolContainer(bool_field=expected)
+
+
+@pytest.mark.parametrize("value", ["t", "y", "maybe", ""])
+def test_bool_field_bool_strings_invalid(value: str) -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class BoolContainer:
+        bool_field: bool = dataclasses.field(metadata=mr.bool_metadata(bool_strings=True))
+
+    with pytest.raises(m.ValidationError) as exc_info:
+        mr.load(BoolContainer, dict(bool_field=value))
+    assert exc_info.value.messages == {"bool_field": ["Not a valid boolean."]}
+
+
+def test_none_as_zero() -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class NumbersContainer:
+        decimal_field: decimal.Decimal = dataclasses.field(metadata=mr.decimal_metadata(none_as_zero=True))
+        float_field: float = dataclasses.field(metadata=mr.float_metadata(none_as_zero=True))
+        optional_float_field: float | None = dataclasses.field(
+            default=None, metadata=mr.float_metadata(none_as_zero=True)
+        )
+
+    loaded = mr.load(NumbersContainer, dict(decimal_field=None, float_field=None, optional_float_field=None))
+    assert loaded == NumbersContainer(decimal_field=decimal.Decimal("0"), float_field=0.0, optional_float_field=None)
+
+
+def test_dump_keeps_declaration_order() -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class Inner:
+        z: int
+        a: int
+        m: int
+
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class Outer:
+        zz: Inner
+        b: str
+        aa: Inner | None
+        inners: list[Inner]
+
+    inner = Inner(z=1, a=2, m=3)
+    dumped = mr.dump(Outer(zz=inner, b="b", aa=inner, inners=[inner]))
+
+    assert list(dumped) == ["zz", "b", "aa", "inners"]
+    assert list(dumped["zz"]) == ["z", "a", "m"]
+    assert list(dumped["aa"]) == ["z", "a", "m"]
+    assert list(dumped["inners"][0]) == ["z", "a", "m"]
+
+
+def test_computed_fields() -> None:
+    @mr.options(naming_case=mr.CAMEL_CASE, computed_fields={"full_name": str, "name_length": int | None})
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class Person:
+        first_name: str
+        last_name: str
+
+        @property
+        def full_name(self) -> str:
+            return f"{self.first_name} {self.last_name}"
+
+        @property
+        def name_length(self) -> int | None:
+            return None
+
+    person = Person(first_name="John", last_name="Doe")
+    dumped = mr.dump(person)
+
+    assert dumped == dict(firstName="John", lastName="Doe", fullName="John Doe")
+    assert mr.load(Person, dumped) == person
+
+
+def test_load_only_and_dump_only() -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class Account:
+        login: str
+        password: str = dataclasses.field(metadata=mr.metadata(load_only=True))
+        id: int = dataclasses.field(default=0, metadata=mr.metadata(dump_only=True))
+
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class Accounts:
+        accounts: list[Account]
+
+    loaded = mr.load(Account, dict(login="john", password="secret", id=42))
+    assert loaded == Account(login="john", password="secret", id=0)
+
+    assert mr.dump(Account(login="john", password="secret", id=42)) == dict(login="john", id=42)
+    assert mr.dump(Accounts(accounts=[Account(login="john", password="secret", id=42)])) == dict(
+        accounts=[dict(login="john", id=42)]
+    )
+
+
+def test_load_only_in_renamed_nested_field() -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class Account:
+        login: str
+        password: str = dataclasses.field(metadata=mr.metadata(load_only=True))
+
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class Accounts:
+        accounts_list: list[Account]
+        main_account: Account = dataclasses.field(metadata=mr.metadata(name="main"))
+
+    accounts = Accounts(
+        accounts_list=[Account(login="john", password="secret")],
+        main_account=Account(login="jane", password="secret"),
+    )
+
+    assert mr.dump(accounts, naming_case=mr.CAMEL_CASE) == dict(
+        accountsList=[dict(login="john")], main=dict(login="jane")
+    )
+
+
+def test_dump_only_requires_default() -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class Account:
+        id: int = dataclasses.field(metadata=mr.metadata(dump_only=True))
+
+    with pytest.raises(ValueError):
+        mr.schema(Account)
+
+
+def test_validate() -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class Container:
+        int_field: int
+        str_field: str
+
+    assert mr.validate(Container, dict(int_field=1, str_field="a")) is None
+    assert mr.validate(Container, dict(int_field="invalid")) == {
+        "int_field": ["Not a valid integer."],
+        "str_field": ["Missing data for required field."],
+    }
+
+
+def test_validate_does_not_construct_objects() -> None:
+    post_loaded: list[Any] = []
+
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class Nested:
+        int_field: int
+
+        def __post_init__(self) -> None:
+            raise ValueError("constructed")
+
+    @mr.options(post_load=post_loaded.append)
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class Container:
+        nested: Nested
+        nested_list: list[Nested]
+
+        def __post_init__(self) -> None:
+            raise ValueError("constructed")
+
+    assert mr.validate(Container, dict(nested=dict(int_field=1), nested_list=[dict(int_field=2)])) is None
+    assert mr.validate(Container, dict(nested=dict(int_field="invalid"), nested_list=[])) == {
+        "nested": {"int_field": ["Not a valid integer."]}
+    }
+    assert post_loaded == []
+
+
+def test_decimal_field_scientific() -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class DecimalContainer:
+        decimal_field: decimal.Decimal = dataclasses.field(metadata=mr.decimal_metadata(places=None, scientific=True))
+
+    container = DecimalContainer(decimal_field=decimal.Decimal("1234.50"))
+
+    assert mr.dump(container) == dict(decimal_field="1.23450E+3")
+    assert mr.load(DecimalContainer, dict(decimal_field="1.23450E+3")) == container
+
+
+def test_dict_with_typed_keys_and_values() -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class DictContainer:
+        int_keys: dict[int, str]
+        uuid_keys: dict[uuid.UUID, decimal.Decimal]
+
+    key = uuid.UUID("e27a2c0d-1f0a-4d0b-9ac4-8b4b2d4c6a9e")
+    loaded = mr.load(DictContainer, dict(int_keys={"1": "one", "2": "two"}, uuid_keys={str(key): "1.50"}))
+
+    assert loaded == DictContainer(int_keys={1: "one", 2: "two"}, uuid_keys={key: decimal.Decimal("1.50")})
+    assert mr.dump(loaded) == dict(int_keys={"1": "one", "2": "two"}, uuid_keys={str(key): "1.50"})
+
+
+def test_dict_with_typed_keys_invalid() -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class DictContainer:
+        dict_field: dict[int, int]
+
+    with pytest.raises(m.ValidationError) as exc_info:
+        mr.load(DictContainer, dict(dict_field={"1": 1, "one": 1, "2": "two"}))
+
+    assert exc_info.value.messages == {"dict_field": {"one": ["Not a valid integer."], "2": ["Not a valid integer."]}}
+
+
+def test_dict_entries() -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class DictContainer:
+        dict_field: dict[int, str] = dataclasses.field(metadata=mr.dict_metadata(entries=True))
+
+    container = DictContainer(dict_field={2: "two", 1: "one"})
+
+    dumped = mr.dump(container)
+
+    assert dumped == dict(dict_field=[[2, "two"], [1, "one"]])
+    assert mr.load(DictContainer, dumped) == container
+    assert list(mr.load(DictContainer, dumped).dict_field) == [2, 1]
+    assert mr.load(DictContainer, dict(dict_field={"1": "one"})) == DictContainer(dict_field={1: "one"})
+
+    with pytest.raises(m.ValidationError) as exc_info:
+        mr.load(DictContainer, dict(dict_field=[[1, "one"], ["two", "two"], [3]]))
+    assert exc_info.value.messages == {"dict_field": {1: ["Not a valid integer."], 2: ["Not a valid entry."]}}
+
+
+def test_custom_field() -> None:
+    @dataclasses.dataclass(frozen=True, slots=True)
+    class Money:
+        amount: decimal.Decimal
+        currency: str
+
+    def serialize_money(value: Money) -> str:
+        return f"{value.amount} {value.currency}"
+
+    def deserialize_money(value: Any) -> Money:
+        amount, currency = value.split(" ")
+        return Money(decimal.Decimal(amount), currency)
+
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class Payment:
+        price: Money = dataclasses.field(
+            metadata=mr.custom_metadata(serializer=serialize_money, deserializer=deserialize_money)
+        )
+        fee: Money | None = dataclasses.field(
+            default=None, metadata=mr.custom_metadata(serializer=serialize_money, deserializer=deserialize_money)
+        )
+
+    payment = Payment(price=Money(decimal.Decimal("9.99"), "USD"))
+
+    assert mr.dump(payment) == dict(price="9.99 USD")
+    assert mr.load(Payment, dict(price="9.99 USD", fee=None)) == payment
+
+    with pytest.raises(m.ValidationError) as exc_info:
+        mr.load(Payment, dict(price="9.99"))
+    assert exc_info.value.messages == {"price": ["Invalid value."]}
+
+
+def test_uuid_field_version() -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class UuidContainer:
+        uuid_field: uuid.UUID = dataclasses.field(metadata=mr.uuid_metadata(version=4))
+
+    uuid4 = uuid.UUID("e27a2c0d-1f0a-4d0b-9ac4-8b4b2d4c6a9e")
+    uuid1 = uuid.UUID("c232ab00-9414-11ec-b3c8-9f6bdeced846")
+
+    assert mr.load(UuidContainer, dict(uuid_field=str(uuid4))) == UuidContainer(uuid_field=uuid4)
+    assert mr.dump(UuidContainer(uuid_field=uuid4)) == dict(uuid_field=str(uuid4))
+
+    with pytest.raises(m.ValidationError) as exc_info:
+        mr.load(UuidContainer, dict(uuid_field=str(uuid1)))
+    assert exc_info.value.messages == {"uuid_field": ["Expected UUID version 4."]}
+
+    with pytest.raises(m.ValidationError) as exc_info:
+        mr.dump(UuidContainer(uuid_field=uuid1))
+    assert exc_info.value.messages == {"uuid_field": ["Expected UUID version 4."]}
+
+
+def test_date_field_with_datetime_value() -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class DateContainer:
+        date_field: datetime.date
+
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class LenientDateContainer:
+        date_field: datetime.date = dataclasses.field(metadata=mr.date_metadata(accept_datetime=True))
+
+    value = datetime.datetime(2022, 2, 20, 11, 33, 48, tzinfo=datetime.timezone.utc)
+
+    assert mr.dump(DateContainer(date_field=value)) == dict(date_field="2022-02-20")
+
+    for invalid in (value, datetime.date(2022, 2, 20)):
+        with pytest.raises(m.ValidationError) as exc_info:
+            mr.load(DateContainer, dict(date_field=invalid))
+        assert exc_info.value.messages == {"date_field": ["Not a valid date."]}
+
+    loaded = mr.load(LenientDateContainer, dict(date_field=value))
+    assert loaded == LenientDateContainer(date_field=datetime.date(2022, 2, 20))
+    assert type(loaded.date_field) is datetime.date
+
+
+def test_named_tuple() -> None:
+    class Point(NamedTuple):
+        x: int
+        y: int
+        label: str | None = None
+
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class Shape:
+        points: list[Point]
+
+    shape = Shape(points=[Point(x=1, y=2, label="a"), Point(x=3, y=4)])
+
+    assert mr.dump(shape) == dict(points=[dict(x=1, y=2, label="a"), dict(x=3, y=4)])
+    assert mr.load(Shape, dict(points=[dict(x=1, y=2, label="a"), dict(x=3, y=4)])) == shape
+    assert mr.load(Shape, dict(points=[[1, 2, "a"], [3, 4]])) == shape
+    assert mr.load(Point, dict(x=1, y=2)) == Point(x=1, y=2)
+
+
+def test_named_tuple_invalid() -> None:
+    class Point(NamedTuple):
+        x: int
+        y: int
+
+    with pytest.raises(m.ValidationError) as exc_info:
+        mr.load(Point, cast(Any, [1]))
+    assert exc_info.value.messages == {"y": ["Missing data for required field."]}
+
+    with pytest.raises(m.ValidationError) as exc_info:
+        mr.load(Point, cast(Any, [1, 2, 3]))
+    assert exc_info.value.messages == {"_schema": ["Too many items, expected at most 2."]}
+
+
+def test_named_tuple_positional() -> None:
+    @mr.options(positional=True)
+    class Point(NamedTuple):
+        x: int
+        y: int
+        label: str | None = None
+
+    assert mr.dump(Point(x=1, y=2)) == [1, 2, None]
+    assert mr.load(Point, cast(Any, [1, 2, "a"])) == Point(x=1, y=2, label="a")
+    assert mr.load(Point, dict(x=1, y=2)) == Point(x=1, y=2)
+
+
+def test_positional() -> None:
+    @mr.options(positional=True)
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class Point:
+        x: int
+        y: int = dataclasses.field(metadata=mr.metadata(name="why"))
+        label: str | None = None
+
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class Shape:
+        points: list[Point]
+
+    shape = Shape(points=[Point(x=1, y=2, label="a"), Point(x=3, y=4)])
+
+    assert mr.dump(shape) == dict(points=[[1, 2, "a"], [3, 4, None]])
+    assert mr.load(Shape, dict(points=[[1, 2, "a"], [3, 4]])) == shape
+    assert mr.load(Shape, dict(points=[dict(x=1, why=2, label="a"), dict(x=3, why=4)])) == shape
+
+    with pytest.raises(m.ValidationError) as exc_info:
+        mr.load(Point, cast(Any, [1]))
+    assert exc_info.value.messages == {"why": ["Missing data for required field."]}
+
+    with pytest.raises(m.ValidationError) as exc_info:
+        mr.load(Point, cast(Any, [1, 2, "a", "b"]))
+    assert exc_info.value.messages == {"_schema": ["Too many items, expected at most 3."]}
+
+
+def test_positional_with_computed_fields() -> None:
+    @mr.options(positional=True, computed_fields={"length": float})
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class Vector:
+        x: float
+        y: float
+
+        @property
+        def length(self) -> float:
+            return math.hypot(self.x, self.y)
+
+    with pytest.raises(ValueError):
+        mr.schema(Vector)
+
+
+def test_datetime_field_with_formats_truncates_nanoseconds() -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class DateTimeContainer:
+        datetime_field: datetime.datetime = dataclasses.field(
+            metadata=mr.datetime_metadata(formats=["%Y-%m-%d %H:%M:%S.%f"])
+        )
+
+    loaded = mr.load(DateTimeContainer, dict(datetime_field="2022-02-20 11:33:48.607289123"))
+    assert loaded == DateTimeContainer(
+        datetime_field=datetime.datetime(2022, 2, 20, 11, 33, 48, 607289, datetime.timezone.utc)
+    )
+
+
+def test_dict_field_with_mappings() -> None:
+    class Settings(Mapping[str, Any]):
+        def __getitem__(self, key: str) -> Any:
+            if key != "key":
+                raise KeyError(key)
+            return "value"
+
+        def __len__(self) -> int:
+            return 1
+
+        def __iter__(self) -> Iterator[str]:
+            return iter(["key"])
+
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class DictContainer:
+        dict_field: dict[str, Any]
+        typed_dict_field: dict[str, int]
+
+    for value in (collections.OrderedDict(key="value"), collections.defaultdict(str, key="value"), Settings()):
+        dumped = mr.dump(DictContainer(dict_field=cast(Any, value), typed_dict_field=collections.OrderedDict(key=1)))
+
+        assert dumped == dict(dict_field=dict(key="value"), typed_dict_field=dict(key=1))
+        assert type(dumped["dict_field"]) is dict
+        assert type(dumped["typed_dict_field"]) is dict
+
+
+def test_default_without_null() -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class BoolContainer:
+        bool_field: bool = dataclasses.field(default=False, metadata=mr.metadata(allow_none=False))
+
+    assert mr.load(BoolContainer, {}) == BoolContainer(bool_field=False)
+    assert mr.load(BoolContainer, dict(bool_field=True)) == BoolContainer(bool_field=True)
+
+    with pytest.raises(m.ValidationError) as exc_info:
+        mr.load(BoolContainer, dict(bool_field=None))
+    assert exc_info.value.messages == {"bool_field": ["Field may not be null."]}
+
+
+def test_fraction_field() -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class FractionContainer:
+        fraction_field: fractions.Fraction
+        optional_fraction_field: fractions.Fraction | None = None
+
+    container = FractionContainer(fraction_field=fractions.Fraction(-2, 6))
+
+    assert mr.dump(container) == dict(fraction_field="-1/3")
+    assert mr.load(FractionContainer, dict(fraction_field="-1/3")) == container
+
+
+@pytest.mark.parametrize("value", ["1", "1/0", "1/-3", "1.5/2", 1])
+def test_fraction_field_invalid(value: Any) -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class FractionContainer:
+        fraction_field: fractions.Fraction
+
+    with pytest.raises(m.ValidationError) as exc_info:
+        mr.load(FractionContainer, dict(fraction_field=value))
+    assert exc_info.value.messages == {"fraction_field": ["Not a valid fraction."]}
+
+
+def test_decimal_field_tag() -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class DecimalContainer:
+        decimal_field: decimal.Decimal = dataclasses.field(metadata=mr.decimal_metadata(tag="__decimal__"))
+
+    container = DecimalContainer(decimal_field=decimal.Decimal("1.23"))
+
+    assert mr.load(DecimalContainer, dict(decimal_field={"__decimal__": "1.23"})) == container
+    assert mr.load(DecimalContainer, dict(decimal_field="1.23")) == container
+
+    with pytest.raises(m.ValidationError):
+        mr.load(DecimalContainer, dict(decimal_field={"__decimal__": "1.23", "extra": 1}))
+
+
+def test_decimal_field_dump_places() -> None:
+    @dataclasses.dataclass(frozen=True, slots=True, kw_only=True)
+    class DecimalContainer:
+        decimal_field: decimal.Decimal = dataclasses.field(metadata=mr.decimal_metadata(places=None, dump_places=2))
+
+    loaded = mr.load(DecimalContainer, dict(decimal_field="1.23456"))
+    assert loaded == DecimalContainer(decimal_field=decimal.Decimal("1.23456"))
+    assert mr.dump(loaded) == dict(decimal_field="1.23")
+    assert mr.dump(DecimalContainer(decimal_field=decimal.Decimal("1"))) == dict(decimal_field="1.00")
@@ -2,6 +2,7 @@ import dataclasses
 import datetime
 import decimal
 import uuid
+from typing import Any
 
 import marshmallow as m
 import pytest
@@ -101,3 +102,66 @@ def test_datetime_validation() -> None:
         mr.load(Holder, dict(value=invalid.isoformat()))
 
     mr.load(Holder, dict(value=datetime.datetime(2001, 1, 2).isoformat()))
+
+
+def test_list_validation() -> None:
+    @dataclasses.dataclass
+    class Holder:
+        value: list[int] = dataclasses.field(
+            metadata=mr.metadata(validate=m.validate.Length(max=2, error="Too many items (limit {max})."))
+        )
+
+    with pytest.raises(m.ValidationError) as exc_info:
+        mr.load(Holder, dict(value=[1, 2, 3]))
+    assert exc_info.value.messages == {"value": ["Too many items (limit 2)."]}
+
+    mr.load(Holder, dict(value=[1, 2]))
+
+
+def test_dict_validation() -> None:
+    @dataclasses.dataclass
+    class Holder:
+        value: dict[str, Any] = dataclasses.field(
+            metadata=mr.metadata(validate=m.validate.Length(max=2, error="Too many items (limit {max})."))
+        )
+
+    with pytest.raises(m.ValidationError) as exc_info:
+        mr.load(Holder, dict(value=dict(a=1, b=2, c=3)))
+    assert exc_info.value.messages == {"value": ["Too many items (limit 2)."]}
+
+    mr.load(Holder, dict(value=dict(a=1, b=2)))
+
+
+def test_max_items() -> None:
+    @mr.options(max_items=2)
+    @dataclasses.dataclass
+    class Holder:
+        value: list[list[int]]
+
+    with pytest.raises(m.ValidationError) as exc_info:
+        mr.load(Holder, dict(value=[[1], [2], [3]]))
+    assert exc_info.value.messages == {"value": ["Too many items (limit 2)."]}
+
+    with pytest.raises(m.ValidationError) as exc_info:
+        mr.load(Holder, dict(value=[[1, 2, 3]]))
+    assert exc_info.value.messages == {"value": {0: ["Too many items (limit 2)."]}}
+
+    assert mr.load(Holder, dict(value=[[1, 2], [3]])) == Holder(value=[[1, 2], [3]])
+
+
+def test_max_keys() -> None:
+    @mr.options(max_keys=2)
+    @dataclasses.dataclass
+    class Holder:
+        value: dict[str, Any]
+        typed_value: dict[str, int] | None = None
+
+    with pytest.raises(m.ValidationError) as exc_info:
+        mr.load(Holder, dict(value=dict(a=1, b=2, c=3)))
+    assert exc_info.value.messages == {"value": ["Too many keys (limit 2)."]}
+
+    with pytest.raises(m.ValidationError) as exc_info:
+        mr.load(Holder, dict(value={}, typed_value=dict(a=1, b=2, c=3)))
+    assert exc_info.value.messages == {"typed_value": ["Too many keys (limit 2)."]}
+
+    assert mr.load(Holder, dict(value=dict(a=1, b=2))) == Holder(value=dict(a=1, b=2))